@@ -0,0 +1,60 @@
+//! Generates the solver registry from the `src/y<year>/day<N>.rs` files. The modules
+//! themselves are declared in `src/main.rs` and `src/y<year>/mod.rs`, which `aoc new`
+//! keeps up to date.
+
+use std::{
+    env, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+fn numbered(name: &str, prefix: &str, suffix: &str) -> Option<u32> {
+    name.strip_prefix(prefix)?
+        .strip_suffix(suffix)?
+        .parse()
+        .ok()
+}
+
+fn find_days(src: &Path) -> io::Result<Vec<(u32, Vec<u32>)>> {
+    let mut years = Vec::new();
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let year = match numbered(&name.to_string_lossy(), "y", "") {
+            Some(y) if entry.file_type()?.is_dir() => y,
+            _ => continue,
+        };
+
+        let mut days = Vec::new();
+        for day in fs::read_dir(entry.path())? {
+            if let Some(d) = numbered(&day?.file_name().to_string_lossy(), "day", ".rs") {
+                days.push(d);
+            }
+        }
+        days.sort();
+        if !days.is_empty() {
+            years.push((year, days));
+        }
+    }
+    years.sort();
+    Ok(years)
+}
+
+fn main() -> io::Result<()> {
+    let src = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap());
+    println!("cargo::rerun-if-changed={}", src.display());
+
+    let years = find_days(&src)?;
+
+    let mut registry = fs::File::create(out.join("registry.rs"))?;
+    writeln!(registry, "static SOLVERS: &[&dyn DynSolver] = &[")?;
+    for (year, days) in &years {
+        for day in days {
+            writeln!(registry, "    crate::y{year}::day{day}::SOLVER,")?;
+        }
+    }
+    writeln!(registry, "];")?;
+
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(i128),
+    Text(String),
+    #[default]
+    NotImplemented,
+}
+
+impl Answer {
+    pub fn is_implemented(&self) -> bool {
+        *self != Answer::NotImplemented
+    }
+
+    /// Formats the answer as a single comma-free field: text is quoted and escaped, and a
+    /// missing answer is written as `-`.
+    pub fn to_field(&self) -> String {
+        match self {
+            Answer::Integer(x) => x.to_string(),
+            Answer::BigInteger(x) => x.to_string(),
+            Answer::NotImplemented => String::from("-"),
+            Answer::Text(s) => {
+                let mut field = String::from('"');
+                for c in s.chars() {
+                    match c {
+                        '"' => field.push_str("\\\""),
+                        '\\' => field.push_str("\\\\"),
+                        '\n' => field.push_str("\\n"),
+                        '\r' => field.push_str("\\r"),
+                        ',' => field.push_str("\\,"),
+                        c => field.push(c),
+                    }
+                }
+                field.push('"');
+                field
+            }
+        }
+    }
+
+    /// Splits a line of comma-separated fields written with [`Answer::to_field`].
+    pub fn split_fields(line: &str) -> impl Iterator<Item = &str> {
+        let mut escaped = false;
+        line.split(move |c| {
+            let split = c == ',' && !escaped;
+            escaped = c == '\\' && !escaped;
+            split
+        })
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(x) => write!(f, "{x}"),
+            Answer::BigInteger(x) => write!(f, "{x}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::NotImplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseAnswerError {
+    UnterminatedText,
+    InvalidEscape(char),
+}
+
+impl FromStr for Answer {
+    type Err = ParseAnswerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() || s == "-" {
+            return Ok(Answer::NotImplemented);
+        }
+
+        let quoted = match s.strip_prefix('"') {
+            Some(q) => q,
+            None => {
+                return Ok(match s.parse::<i128>() {
+                    Ok(x) => x.into(),
+                    Err(_) => Answer::Text(s.to_string()),
+                });
+            }
+        };
+
+        let mut text = String::new();
+        let mut chars = quoted.chars();
+        loop {
+            match chars.next() {
+                None => return Err(ParseAnswerError::UnterminatedText),
+                Some('"') => break,
+                Some('\\') => match chars.next() {
+                    Some('n') => text.push('\n'),
+                    Some('r') => text.push('\r'),
+                    Some(c @ ('"' | '\\' | ',')) => text.push(c),
+                    Some(c) => return Err(ParseAnswerError::InvalidEscape(c)),
+                    None => return Err(ParseAnswerError::UnterminatedText),
+                },
+                Some(c) => text.push(c),
+            }
+        }
+        Ok(Answer::Text(text))
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(x: i128) -> Self {
+        match i64::try_from(x) {
+            Ok(x) => Answer::Integer(x),
+            Err(_) => Answer::BigInteger(x),
+        }
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(x: u128) -> Self {
+        match i128::try_from(x) {
+            Ok(x) => x.into(),
+            Err(_) => Answer::Text(x.to_string()),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(x: $t) -> Self {
+                    (x as i128).into()
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use crate::answer::Answer;
+
+    #[rstest]
+    #[case(Answer::from(42u64))]
+    #[case(Answer::from(-7i64))]
+    #[case(Answer::from(u64::MAX))]
+    #[case(Answer::from("6,0,1"))]
+    #[case(Answer::from("####\n#..#\n\"quoted\" \\"))]
+    #[case(Answer::NotImplemented)]
+    fn test_field_round_trip(#[case] answer: Answer) {
+        assert_eq!(answer, answer.to_field().parse().unwrap());
+    }
+
+    #[test]
+    fn test_integers_normalise() {
+        assert_eq!(Answer::Integer(5), Answer::from(5u128));
+        assert_eq!(Answer::BigInteger(1 << 64), Answer::from(1u128 << 64));
+    }
+
+    #[test]
+    fn test_split_fields() {
+        let line = format!(
+            "3,{},{}",
+            Answer::from("a,b").to_field(),
+            Answer::from(7).to_field()
+        );
+        let fields: Vec<Answer> = Answer::split_fields(&line)
+            .map(|f| f.parse().unwrap())
+            .collect();
+        assert_eq!(
+            vec![Answer::from(3), Answer::from("a,b"), Answer::from(7)],
+            fields
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use std::{fmt, time::Duration};
+
+use crate::{
+    isolate::{IsolatedRun, run_isolated},
+    solver::{Context, DynSolver, Timings},
+};
+
+/// Time each day is allowed to take, parse and both parts together.
+pub const BUDGET: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarises `samples`, or returns `None` if there are none.
+    pub fn new(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        let variance = sorted
+            .iter()
+            .map(|x| (x.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Some(Stats {
+            min: sorted[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayBench {
+    pub year: u16,
+    pub day: u32,
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+    pub total: Stats,
+}
+
+impl DayBench {
+    fn new(year: u16, day: u32, timings: &[Timings]) -> Option<Self> {
+        let stats =
+            |f: fn(&Timings) -> Duration| Stats::new(&timings.iter().map(f).collect::<Vec<_>>());
+        Some(DayBench {
+            year,
+            day,
+            runs: timings.len(),
+            parse: stats(|t| t.parse)?,
+            part1: stats(|t| t.part1)?,
+            part2: stats(|t| t.part2)?,
+            total: stats(|t| t.total())?,
+        })
+    }
+
+    pub fn over_budget(&self) -> bool {
+        self.total.median > BUDGET
+    }
+}
+
+/// Runs `solver` on `input` `runs` times, each part isolated and limited as by
+/// [`run_isolated`]. Returns the first run and the timings of all of them, or, as soon as
+/// a run has a part that fails or times out, that run and no timings.
+pub fn bench(
+    solver: &'static dyn DynSolver,
+    input: &str,
+    ctx: &Context,
+    runs: u32,
+    limit: Option<Duration>,
+) -> (IsolatedRun, Option<DayBench>) {
+    let mut first: Option<IsolatedRun> = None;
+    let mut timings = Vec::new();
+    for _ in 0..runs {
+        let run = run_isolated(solver, input, ctx, limit);
+        if run.parts.iter().any(|p| p.is_err()) {
+            return (run, None);
+        }
+        timings.push(run.timings);
+        first.get_or_insert(run);
+    }
+    let stats = DayBench::new(solver.year(), solver.day(), &timings);
+    (first.expect("at least one run was timed"), stats)
+}
+
+impl fmt::Display for DayBench {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "  {:<8} {:>12} {:>12} {:>12} {:>12}   ({} runs)",
+            "", "min", "median", "mean", "stddev", self.runs
+        )?;
+        for (name, s) in [
+            ("parse", &self.parse),
+            ("part 1", &self.part1),
+            ("part 2", &self.part2),
+            ("total", &self.total),
+        ] {
+            writeln!(
+                f,
+                "  {name:<8} {:>12} {:>12} {:>12} {:>12}",
+                format!("{:.2?}", s.min),
+                format!("{:.2?}", s.median),
+                format!("{:.2?}", s.mean),
+                format!("{:.2?}", s.stddev),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Grand total over several benchmarked days, flagging those over [`BUDGET`].
+pub struct Summary<'a>(pub &'a [DayBench]);
+
+impl fmt::Display for Summary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Median time per day (budget {BUDGET:?}):")?;
+        for b in self.0 {
+            let flag = if b.over_budget() { "  OVER BUDGET" } else { "" };
+            writeln!(
+                f,
+                "  {} Day {:>2} {:>12}{flag}",
+                b.year,
+                b.day,
+                format!("{:.2?}", b.total.median)
+            )?;
+        }
+        let total: Duration = self.0.iter().map(|b| b.total.median).sum();
+        let over = self.0.iter().filter(|b| b.over_budget()).count();
+        write!(
+            f,
+            "  Total       {:>12}   {over} of {} days over budget",
+            format!("{total:.2?}"),
+            self.0.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        bench::{BUDGET, DayBench, Stats},
+        solver::Timings,
+    };
+
+    fn ms(x: u64) -> Duration {
+        Duration::from_millis(x)
+    }
+
+    #[test]
+    fn test_stats() {
+        let s = Stats::new(&[ms(4), ms(2), ms(8), ms(6)]).unwrap();
+        assert_eq!(ms(2), s.min);
+        assert_eq!(ms(5), s.median);
+        assert_eq!(ms(5), s.mean);
+        assert!((s.stddev.as_secs_f64() * 1e3 - 5f64.sqrt()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_stats_single_and_empty() {
+        let s = Stats::new(&[ms(3)]).unwrap();
+        assert_eq!((ms(3), ms(3), Duration::ZERO), (s.median, s.mean, s.stddev));
+        assert_eq!(None, Stats::new(&[]));
+    }
+
+    #[test]
+    fn test_over_budget() {
+        let t = Timings {
+            parse: ms(100),
+            part1: ms(400),
+            part2: ms(600),
+            ..Timings::default()
+        };
+        let b = DayBench::new(2025, 1, &[t]).unwrap();
+        assert_eq!(ms(1100), b.total.min);
+        assert!(b.total.median > BUDGET);
+        assert!(b.over_budget());
+    }
+}
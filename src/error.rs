@@ -0,0 +1,167 @@
+use std::{fmt, io, path::PathBuf};
+
+use crate::{
+    fetch::{SESSION_ENV, session_file},
+    helpers::grid::GridError,
+};
+
+#[derive(Debug)]
+pub enum AocError {
+    UnknownDay {
+        year: u16,
+        day: u32,
+    },
+    MissingInput(PathBuf),
+    Io(PathBuf, io::Error),
+    Parse {
+        line: Option<usize>,
+        column: Option<usize>,
+        message: String,
+    },
+    Solver(String),
+    MissingSession,
+    NotUnlocked {
+        year: u16,
+        day: u32,
+    },
+    BadSession,
+    Server(u16),
+    Http(String),
+    SubmitRefused(String),
+    Param(String),
+    AlreadyExists(PathBuf),
+    /// The part ran out of time and stopped when asked to.
+    Cancelled,
+}
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+impl AocError {
+    /// A parse failure on `line`, counted from 1.
+    pub fn parse_line(line: usize, message: impl Into<String>) -> Self {
+        AocError::Parse {
+            line: Some(line),
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// A parse failure at `line` and `column`, both counted from 1.
+    pub fn parse_at(line: usize, column: usize, message: impl Into<String>) -> Self {
+        AocError::Parse {
+            line: Some(line),
+            column: Some(column),
+            message: message.into(),
+        }
+    }
+
+    /// A parse failure that can't be pinned to a position in the input.
+    pub fn parse(message: impl Into<String>) -> Self {
+        AocError::Parse {
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn solver(message: impl Into<String>) -> Self {
+        AocError::Solver(message.into())
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::UnknownDay { year, day } => {
+                write!(f, "no implementation found for {year} Day {day}")
+            }
+            AocError::MissingInput(p) => write!(f, "input not found: {}", p.display()),
+            AocError::Io(p, e) => write!(f, "{}: {e}", p.display()),
+            AocError::Parse {
+                line,
+                column,
+                message,
+            } => {
+                write!(f, "parse error")?;
+                match (line, column) {
+                    (Some(l), Some(c)) => write!(f, " at line {l}, column {c}")?,
+                    (Some(l), None) => write!(f, " at line {l}")?,
+                    _ => {}
+                }
+                write!(f, ": {message}")
+            }
+            AocError::Solver(message) => write!(f, "solver failed: {message}"),
+            AocError::MissingSession => write!(
+                f,
+                "no session token: set {SESSION_ENV} or write it to {}",
+                session_file().display()
+            ),
+            AocError::NotUnlocked { year, day } => {
+                write!(f, "{year} Day {day} is not unlocked yet")
+            }
+            AocError::BadSession => write!(f, "the session token was rejected"),
+            AocError::Server(status) => write!(f, "server error (HTTP {status})"),
+            AocError::Http(message) => write!(f, "request failed: {message}"),
+            AocError::SubmitRefused(reason) => write!(f, "not submitting: {reason}"),
+            AocError::Param(message) => write!(f, "{message}"),
+            AocError::AlreadyExists(p) => write!(f, "{} already exists", p.display()),
+            AocError::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+impl From<GridError> for AocError {
+    fn from(e: GridError) -> Self {
+        match e {
+            GridError::MismatchedColumns { row } => {
+                AocError::parse_line(row + 1, "row length differs from the first row")
+            }
+            GridError::InvalidCell { row, col, value } => {
+                AocError::parse_at(row + 1, col + 1, format!("invalid cell '{value}'"))
+            }
+            GridError::PointNotInGrid => AocError::solver("point not in grid"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::AocError,
+        helpers::{grid::Grid, parse::parse_lines},
+    };
+
+    #[test]
+    fn test_display_position() {
+        assert_eq!(
+            "parse error at line 3, column 2: bad",
+            AocError::parse_at(3, 2, "bad").to_string()
+        );
+        assert_eq!(
+            "parse error at line 3: bad",
+            AocError::parse_line(3, "bad").to_string()
+        );
+        assert_eq!("parse error: bad", AocError::parse("bad").to_string());
+    }
+
+    #[test]
+    fn test_parse_lines_reports_line() {
+        let res: Result<Vec<u32>, AocError> = parse_lines("1\n2\nx").collect();
+        assert!(matches!(res, Err(AocError::Parse { line: Some(3), .. })));
+    }
+
+    #[test]
+    fn test_grid_error_position() {
+        let res = "12\n3x".parse::<Grid<u32>>().map_err(AocError::from);
+        assert!(matches!(
+            res,
+            Err(AocError::Parse {
+                line: Some(2),
+                column: Some(2),
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,224 @@
+//! Example inputs from the puzzle text, stored next to the real inputs as
+//! `<year>/day<N>.example.txt`, `day<N>.example2.txt`, ... Each file starts with a header
+//! of `key: value` lines, then a `---` line, then the input itself:
+//!
+//! ```text
+//! part1: 40
+//! part2: 25272
+//! param: connections=10
+//! ---
+//! 162,817,812
+//! ...
+//! ```
+//!
+//! `part1` and `part2` are the expected answers, and either may be left out. Each
+//! `param` line overrides one of the solver's parameters for this example.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answer::Answer,
+    error::{AocError, Result},
+    input::read_input,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub expected: (Answer, Answer),
+    pub params: Vec<(String, String)>,
+    pub input: String,
+}
+
+impl Example {
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut expected = (Answer::NotImplemented, Answer::NotImplemented);
+        let mut params = Vec::new();
+        let mut lines = text.split('\n').enumerate();
+        loop {
+            let Some((idx, line)) = lines.next() else {
+                return Err(AocError::parse("missing '---' line before the input"));
+            };
+            let line = line.trim();
+            if line == "---" {
+                break;
+            }
+            if line.is_empty() {
+                continue;
+            }
+
+            let err = |msg: String| AocError::parse_line(idx + 1, msg);
+            let Some((key, value)) = line.split_once(':') else {
+                return Err(err(format!("expected 'key: value', got '{line}'")));
+            };
+            let answer = |v: &str| {
+                v.parse::<Answer>()
+                    .map_err(|e| err(format!("invalid answer: {e:?}")))
+            };
+            match key.trim() {
+                "part1" => expected.0 = answer(value)?,
+                "part2" => expected.1 = answer(value)?,
+                "param" => match value.trim().split_once('=') {
+                    Some((k, v)) => params.push((k.trim().to_string(), v.trim().to_string())),
+                    None => return Err(err(format!("expected 'param: key=value', got '{line}'"))),
+                },
+                k => return Err(err(format!("unknown key '{k}'"))),
+            }
+        }
+
+        let input = lines.map(|(_, line)| line).collect::<Vec<_>>().join("\n");
+        Ok(Example {
+            expected,
+            params,
+            input: input.trim_end_matches(['\n', '\r']).to_string(),
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = read_input(path)?;
+        Self::parse(&text).map_err(|e| match e {
+            AocError::Parse {
+                line,
+                column,
+                message,
+            } => AocError::Parse {
+                line,
+                column,
+                message: format!("{}: {message}", path.display()),
+            },
+            e => e,
+        })
+    }
+}
+
+/// Path of the `n`th example for a day, counted from 1.
+pub fn example_path(dir: &Path, year: u16, day: u32, n: usize) -> PathBuf {
+    let name = match n {
+        1 => format!("day{day}.example.txt"),
+        n => format!("day{day}.example{n}.txt"),
+    };
+    dir.join(year.to_string()).join(name)
+}
+
+/// Every example stored for a day, in order.
+pub fn example_paths(dir: &Path, year: u16, day: u32) -> Result<Vec<PathBuf>> {
+    let year_dir = dir.join(year.to_string());
+    let entries = match fs::read_dir(&year_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(AocError::Io(year_dir, e)),
+    };
+
+    let prefix = format!("day{day}.example");
+    let mut numbered = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| AocError::Io(year_dir.clone(), e))?;
+        let name = entry.file_name();
+        let Some(n) = name
+            .to_str()
+            .and_then(|n| n.strip_prefix(&prefix))
+            .and_then(|n| n.strip_suffix(".txt"))
+        else {
+            continue;
+        };
+        match n {
+            "" => numbered.push((1, entry.path())),
+            n => {
+                if let Ok(n) = n.parse::<usize>() {
+                    numbered.push((n, entry.path()));
+                }
+            }
+        }
+    }
+    numbered.sort();
+    Ok(numbered.into_iter().map(|(_, p)| p).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use crate::{
+        answer::Answer,
+        error::AocError,
+        example::{Example, example_path, example_paths},
+        input::DEFAULT_INPUTS_DIR,
+        isolate::{DEFAULT_TIMEOUT_SECS, time_limit},
+        solver::{Context, registry},
+        verify::{MismatchTable, verify_day},
+    };
+
+    #[test]
+    fn test_parse() {
+        let example =
+            Example::parse("part1: 40\nparam: connections = 10\n---\n1,2,3\n4,5,6\n").unwrap();
+        assert_eq!(
+            Example {
+                expected: (Answer::Integer(40), Answer::NotImplemented),
+                params: vec![("connections".to_string(), "10".to_string())],
+                input: "1,2,3\n4,5,6".to_string(),
+            },
+            example
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            Example::parse("part1: 1\n"),
+            Err(AocError::Parse { line: None, .. })
+        ));
+        assert!(matches!(
+            Example::parse("part1: 1\npart3: 2\n---\n"),
+            Err(AocError::Parse { line: Some(2), .. })
+        ));
+    }
+
+    #[test]
+    fn test_example_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("2025")).unwrap();
+        for n in [10, 2, 1] {
+            fs::write(example_path(dir.path(), 2025, 8, n), "---\n").unwrap();
+        }
+        for other in ["day8.txt", "day80.example.txt", "day8.exampleX.txt"] {
+            fs::write(dir.path().join("2025").join(other), "").unwrap();
+        }
+
+        let names: Vec<String> = example_paths(dir.path(), 2025, 8)
+            .unwrap()
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(
+            vec![
+                "day8.example.txt",
+                "day8.example2.txt",
+                "day8.example10.txt"
+            ],
+            names
+        );
+        assert!(example_paths(dir.path(), 2024, 1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_examples() {
+        for solver in registry() {
+            let (year, day) = (solver.year(), solver.day());
+            for path in example_paths(Path::new(DEFAULT_INPUTS_DIR), year, day).unwrap() {
+                let example = Example::load(&path).unwrap();
+                let ctx = Context::new(solver.params(), &example.params, None).unwrap();
+                let limit = time_limit(DEFAULT_TIMEOUT_SECS);
+                let mismatches = verify_day(solver, &example.input, &ctx, &example.expected, limit);
+                assert!(
+                    mismatches.is_empty(),
+                    "{}\n{}",
+                    path.display(),
+                    MismatchTable(&mismatches)
+                );
+            }
+        }
+    }
+}
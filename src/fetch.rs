@@ -0,0 +1,227 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    error::{AocError, Result},
+    input::input_path,
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the adventofcode.com session cookie.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// Config file the session token is read from when [`SESSION_ENV`] is unset:
+/// `$XDG_CONFIG_HOME/aoc/session`, falling back to `~/.config/aoc/session`.
+pub fn session_file() -> PathBuf {
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))
+        .unwrap_or_default();
+    config.join("aoc").join("session")
+}
+
+pub fn session_token() -> Result<String> {
+    if let Ok(token) = env::var(SESSION_ENV)
+        && !token.trim().is_empty()
+    {
+        return Ok(token.trim().to_string());
+    }
+    let path = session_file();
+    match fs::read_to_string(&path) {
+        Ok(token) if !token.trim().is_empty() => Ok(token.trim().to_string()),
+        Ok(_) => Err(AocError::MissingSession),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(AocError::MissingSession),
+        Err(e) => Err(AocError::Io(path, e)),
+    }
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: String) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    pub fn input(&self, year: u16, day: u32) -> Result<String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        read_response(&url, response, year, day)
+    }
+
+    /// Posts `answer` for `part` and returns the HTML page the server replies with.
+    pub fn answer(&self, year: u16, day: u32, part: u8, answer: &str) -> Result<String> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        read_response(&url, response, year, day)
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: std::result::Result<ureq::Response, ureq::Error>,
+    year: u16,
+    day: u32,
+) -> Result<String> {
+    match response {
+        Ok(r) => r
+            .into_string()
+            .map_err(|e| AocError::Http(format!("{url}: {e}"))),
+        Err(ureq::Error::Status(404, _)) => Err(AocError::NotUnlocked { year, day }),
+        Err(ureq::Error::Status(400, _)) => Err(AocError::BadSession),
+        Err(ureq::Error::Status(status, _)) if status >= 500 => Err(AocError::Server(status)),
+        Err(ureq::Error::Status(status, _)) => {
+            Err(AocError::Http(format!("{url}: unexpected HTTP {status}")))
+        }
+        Err(e) => Err(AocError::Http(e.to_string())),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    Cached(PathBuf),
+}
+
+/// Downloads the input for `year`/`day` into `inputs_dir`, unless it is already there.
+pub fn fetch_input(client: &Client, inputs_dir: &Path, year: u16, day: u32) -> Result<Fetched> {
+    let path = input_path(inputs_dir, year, day);
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = client.input(year, day)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| AocError::Io(dir.to_path_buf(), e))?;
+    }
+    fs::write(&path, input).map_err(|e| AocError::Io(path.clone(), e))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::mpsc::{self, Receiver},
+        thread,
+    };
+
+    /// Serves `responses` in order, one per connection, on a local port. Each request's
+    /// head and body are sent back on the returned channel.
+    pub(crate) fn serve(responses: Vec<(u16, &'static str)>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        content_length = len.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut buf = vec![0; content_length];
+                std::io::Read::read_exact(&mut reader, &mut buf).unwrap();
+                request.push_str(&String::from_utf8_lossy(&buf));
+                tx.send(request).unwrap();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+        (url, rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use rstest::rstest;
+
+    use crate::{
+        error::AocError,
+        fetch::{Client, Fetched, fetch_input, stub},
+        input::input_path,
+    };
+
+    #[test]
+    fn test_fetch_and_cache() {
+        let (url, requests) = stub::serve(vec![(200, "1 2\n3 4\n")]);
+        let dir = tempfile::tempdir().unwrap();
+        let client = Client::new(&url, "abc".to_string());
+
+        let path = input_path(dir.path(), 2025, 1);
+        assert_eq!(
+            Fetched::Downloaded(path.clone()),
+            fetch_input(&client, dir.path(), 2025, 1).unwrap()
+        );
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2025/day/1/input "));
+        assert!(request.contains("session=abc"));
+        assert_eq!("1 2\n3 4\n", fs::read_to_string(&path).unwrap());
+
+        // The stub only answers once, so a second request would fail.
+        assert_eq!(
+            Fetched::Cached(path),
+            fetch_input(&client, dir.path(), 2025, 1).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case(404)]
+    #[case(400)]
+    #[case(502)]
+    fn test_fetch_errors(#[case] status: u16) {
+        let (url, _requests) = stub::serve(vec![(status, "nope")]);
+        let dir = tempfile::tempdir().unwrap();
+        let client = Client::new(&url, "abc".to_string());
+
+        let err = fetch_input(&client, dir.path(), 2025, 12).unwrap_err();
+        match status {
+            404 => assert!(matches!(
+                err,
+                AocError::NotUnlocked {
+                    year: 2025,
+                    day: 12
+                }
+            )),
+            400 => assert!(matches!(err, AocError::BadSession)),
+            _ => assert!(matches!(err, AocError::Server(502))),
+        }
+        assert!(!input_path(dir.path(), 2025, 12).exists());
+    }
+}
@@ -5,6 +5,12 @@ pub struct DirectedAcyclicGraph<T: Debug + Eq + PartialEq + Hash> {
     nodes: HashMap<T, Vec<T>>,
 }
 
+impl<T: Debug + Eq + PartialEq + Hash> Default for DirectedAcyclicGraph<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T: Debug + Eq + PartialEq + Hash> DirectedAcyclicGraph<T> {
     pub fn new() -> Self {
         Self {
@@ -26,12 +32,11 @@ impl<T: Debug + Eq + PartialEq + Hash> DirectedAcyclicGraph<T> {
     pub fn count_paths(&self, start: &T, end: &T) -> usize {
         let mut queue: Vec<&T> = Vec::from(&[start]);
         let mut res = 0;
-        while !queue.is_empty() {
-            let x = queue.pop().unwrap();
+        while let Some(x) = queue.pop() {
             if x == end {
                 res += 1;
             } else {
-                for c in self.get_children(x).unwrap().iter() {
+                for c in self.get_children(x).unwrap_or_default() {
                     queue.push(c);
                 }
             }
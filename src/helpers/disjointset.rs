@@ -5,6 +5,12 @@ pub struct DisjointSet<T: Debug + Eq + Hash + Copy> {
     sets: Vec<HashSet<T>>,
 }
 
+impl<T: Debug + Eq + Hash + Copy> Default for DisjointSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T: Debug + Eq + Hash + Copy> DisjointSet<T> {
     pub fn new() -> Self {
         DisjointSet { sets: Vec::new() }
@@ -51,4 +57,8 @@ impl<T: Debug + Eq + Hash + Copy> DisjointSet<T> {
     pub fn len(&self) -> usize {
         self.sets.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.sets.is_empty()
+    }
 }
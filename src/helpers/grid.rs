@@ -1,6 +1,6 @@
 use std::{
-    fmt::{self, Debug, Display},
-    ops::{Add, AddAssign},
+    fmt::{self, Debug},
+    ops::{Add, AddAssign, Index, IndexMut},
     str::FromStr,
 };
 
@@ -22,6 +22,11 @@ impl Point {
         }
     }
 
+    /// Steps between `self` and `other` moving only along rows and columns.
+    pub fn manhattan(&self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
     pub fn up(&self) -> Self {
         self + Direction::point(&Direction::North)
     }
@@ -143,154 +148,494 @@ impl Direction {
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct Grid<T: Copy + Debug + Display + PartialEq + Eq> {
-    grid: Vec<Vec<T>>,
+/// Which cells around a point count as its neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood<'a> {
+    /// The four orthogonal cells, von Neumann's neighbourhood.
+    VonNeumann,
+    /// All eight surrounding cells, Moore's neighbourhood.
+    Moore,
+    /// The four diagonal cells.
+    Diagonal,
+    /// Any set of offsets from the point.
+    Custom(&'a [Point]),
+}
+
+impl<'a> Neighbourhood<'a> {
+    /// Offsets from a point to its neighbours, clockwise from north for the built-in
+    /// neighbourhoods.
+    pub fn offsets(&self) -> &'a [Point] {
+        const VON_NEUMANN: [Point; 4] = [
+            Point { x: 0, y: -1 },
+            Point { x: 1, y: 0 },
+            Point { x: 0, y: 1 },
+            Point { x: -1, y: 0 },
+        ];
+        const MOORE: [Point; 8] = [
+            Point { x: 0, y: -1 },
+            Point { x: 1, y: -1 },
+            Point { x: 1, y: 0 },
+            Point { x: 1, y: 1 },
+            Point { x: 0, y: 1 },
+            Point { x: -1, y: 1 },
+            Point { x: -1, y: 0 },
+            Point { x: -1, y: -1 },
+        ];
+        const DIAGONAL: [Point; 4] = [
+            Point { x: 1, y: -1 },
+            Point { x: 1, y: 1 },
+            Point { x: -1, y: 1 },
+            Point { x: -1, y: -1 },
+        ];
+
+        match self {
+            Neighbourhood::VonNeumann => &VON_NEUMANN,
+            Neighbourhood::Moore => &MOORE,
+            Neighbourhood::Diagonal => &DIAGONAL,
+            Neighbourhood::Custom(offsets) => offsets,
+        }
+    }
+}
+
+/// A value that is written as a single character, so that a grid of it can be parsed
+/// and displayed.
+pub trait GridCell: Copy + Debug + PartialEq + Eq {
+    /// The cell `c` stands for, `None` if it isn't one.
+    fn from_char(c: char) -> Option<Self>;
+
+    fn to_char(&self) -> char;
+}
+
+impl GridCell for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// A single decimal digit. Values above 9 are shown as `+`.
+impl GridCell for u32 {
+    fn from_char(c: char) -> Option<Self> {
+        c.to_digit(10)
+    }
+
+    fn to_char(&self) -> char {
+        char::from_digit(*self, 10).unwrap_or('+')
+    }
+}
+
+/// `#` for set and `.` for clear.
+impl GridCell for bool {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        if *self { '#' } else { '.' }
+    }
 }
 
-impl<T: Copy + Debug + Display + PartialEq + Eq> fmt::Display for Grid<T> {
+/// A rectangle of cells stored row by row in one `Vec`, addressed by [`Point`]s with `x`
+/// as the column and `y` as the row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T: Copy + Debug + PartialEq + Eq> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: GridCell> fmt::Display for Grid<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for row in &self.grid {
-            for v in row {
-                write!(f, "{v}")?;
+        for r in 0..self.height {
+            for v in self.row(r) {
+                write!(f, "{}", v.to_char())?;
             }
-            write!(f, "\n")?;
+            writeln!(f)?;
         }
         Ok(())
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum GridError {
-    MismatchedColumns,
+    MismatchedColumns { row: usize },
+    InvalidCell { row: usize, col: usize, value: char },
     PointNotInGrid,
 }
 
-impl<T: Copy + Debug + Display + PartialEq + Eq> Grid<T> {
-    fn new(rows: Vec<Vec<T>>) -> Result<Self, GridError> {
-        let col_num = match rows.get(0) {
-            Some(r) => r.len(),
-            None => 0,
-        };
-        for r in rows.iter() {
-            if r.len() != col_num {
-                return Err(GridError::MismatchedColumns);
-            }
+impl<T: Copy + Debug + PartialEq + Eq> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
         }
-
-        return Ok(Self { grid: rows });
     }
 
     pub fn rows(&self) -> usize {
-        self.grid.len()
+        self.height
     }
 
     pub fn cols(&self) -> usize {
-        match self.grid.get(0) {
-            Some(r) => r.len(),
-            None => 0,
+        self.width
+    }
+
+    /// The position of `p` in the cell storage, `None` if it is outside the grid.
+    #[inline]
+    pub fn index_of(&self, p: Point) -> Option<usize> {
+        // A negative coordinate wraps to a value far past any width or height.
+        let (x, y) = (p.x as u64, p.y as u64);
+        if x < self.width as u64 && y < self.height as u64 {
+            Some(y as usize * self.width + x as usize)
+        } else {
+            None
+        }
+    }
+
+    /// The point stored at `index`, which must be below `rows() * cols()`.
+    #[inline]
+    pub fn point_of(&self, index: usize) -> Point {
+        Point {
+            x: (index % self.width) as i64,
+            y: (index / self.width) as i64,
         }
     }
 
     pub fn contains(&self, p: Point) -> bool {
-        p.x >= 0
-            && p.x < self.cols().try_into().unwrap()
-            && p.y >= 0
-            && p.y < self.rows().try_into().unwrap()
+        self.index_of(p).is_some()
     }
 
+    #[inline]
     pub fn get(&self, p: Point) -> Option<T> {
-        if !self.contains(p) {
-            return None;
-        }
+        self.index_of(p).map(|i| self.cells[i])
+    }
 
-        let row_num: usize = p.y.try_into().unwrap();
-        let col_num: usize = p.x.try_into().unwrap();
-        Some(self.grid[row_num][col_num])
+    /// The cells of row `r`, left to right.
+    pub fn row(&self, r: usize) -> &[T] {
+        &self.cells[r * self.width..(r + 1) * self.width]
     }
 
     pub fn walk(&self) -> impl Iterator<Item = (Point, T)> {
-        (0..self.rows()).flat_map(move |r| self.walk_row(r))
+        let width = self.width;
+        self.cells
+            .chunks_exact(width.max(1))
+            .zip(0..)
+            .flat_map(move |(row, y)| row.iter().zip(0..).map(move |(&v, x)| (Point { x, y }, v)))
     }
 
     pub fn walk_row(&self, row: usize) -> impl Iterator<Item = (Point, T)> {
-        (0..self.cols()).map(move |c| {
-            let p = Point::new(c, row);
-            let v = self.get(p).unwrap();
-            (p, v)
-        })
+        let y = row as i64;
+        self.row(row)
+            .iter()
+            .zip(0..)
+            .map(move |(&v, x)| (Point { x, y }, v))
     }
 
+    /// All eight surrounding cells that are inside the grid.
     pub fn iter_adjacent(&self, p: Point) -> impl Iterator<Item = (Point, T)> {
-        Direction::iter()
-            .map(move |d| (p + d, self.get(p + d)))
-            .filter(|(_, v)| v.is_some())
-            .map(|(q, v)| (q, v.unwrap()))
+        self.neighbours(p, Neighbourhood::Moore)
     }
 
-    pub fn search(&self, val: T) -> Option<Point> {
-        match self.walk().filter(|(_, v)| *v == val).next() {
-            Some((p, _)) => Some(p),
-            None => None,
-        }
+    /// The neighbours of `p` that are inside the grid.
+    pub fn neighbours<'a>(
+        &'a self,
+        p: Point,
+        n: Neighbourhood<'a>,
+    ) -> impl Iterator<Item = (Point, T)> + 'a {
+        n.offsets().iter().filter_map(move |d| {
+            let q = p + *d;
+            self.get(q).map(|v| (q, v))
+        })
     }
 
-    pub fn update(&mut self, p: Point, v: T) -> Result<(), GridError> {
-        if !self.contains(p) {
-            return Err(GridError::PointNotInGrid);
+    /// The neighbours of `p` with the grid wrapped around at its edges like a torus, so
+    /// every offset yields a cell unless the grid is empty.
+    pub fn neighbours_wrapping<'a>(
+        &'a self,
+        p: Point,
+        n: Neighbourhood<'a>,
+    ) -> impl Iterator<Item = (Point, T)> + 'a {
+        let offsets = if self.cells.is_empty() {
+            &[]
+        } else {
+            n.offsets()
+        };
+        offsets.iter().map(move |d| {
+            let q = self.wrap(p + *d);
+            (q, self[q])
+        })
+    }
+
+    /// The points of the neighbours of `p` whose value satisfies `pred`.
+    pub fn neighbours_matching<'a>(
+        &'a self,
+        p: Point,
+        n: Neighbourhood<'a>,
+        pred: impl Fn(T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.neighbours(p, n)
+            .filter_map(move |(q, v)| pred(v).then_some(q))
+    }
+
+    /// How many neighbours of `p` have a value satisfying `pred`.
+    pub fn count_neighbours(
+        &self,
+        p: Point,
+        n: Neighbourhood<'_>,
+        pred: impl Fn(T) -> bool,
+    ) -> usize {
+        self.neighbours(p, n).filter(|&(_, v)| pred(v)).count()
+    }
+
+    /// `p` moved into the grid as if its edges were joined. The grid must not be empty.
+    pub fn wrap(&self, p: Point) -> Point {
+        Point {
+            x: p.x.rem_euclid(self.width as i64),
+            y: p.y.rem_euclid(self.height as i64),
         }
+    }
+
+    pub fn search(&self, val: T) -> Option<Point> {
+        self.cells
+            .iter()
+            .position(|v| *v == val)
+            .map(|i| self.point_of(i))
+    }
 
-        let row_num: usize = p.y.try_into().unwrap();
-        let col_num: usize = p.x.try_into().unwrap();
-        self.grid[row_num][col_num] = v;
+    pub fn update(&mut self, p: Point, v: T) -> Result<(), GridError> {
+        let i = self.index_of(p).ok_or(GridError::PointNotInGrid)?;
+        self.cells[i] = v;
         Ok(())
     }
 
     pub fn swap(&mut self, p: Point, q: Point) -> Result<(), GridError> {
-        let a = match self.get(p) {
-            Some(v) => v,
-            None => return Err(GridError::PointNotInGrid),
-        };
+        match (self.index_of(p), self.index_of(q)) {
+            (Some(i), Some(j)) => {
+                self.cells.swap(i, j);
+                Ok(())
+            }
+            _ => Err(GridError::PointNotInGrid),
+        }
+    }
+}
 
-        let b = match self.get(q) {
-            Some(v) => v,
-            None => return Err(GridError::PointNotInGrid),
-        };
+impl<T: Copy + Debug + PartialEq + Eq> Index<Point> for Grid<T> {
+    type Output = T;
 
-        self.update(p, b).unwrap();
-        self.update(q, a).unwrap();
-        Ok(())
+    /// Panics if `p` is outside the grid; use [`Grid::get`] when it may be.
+    fn index(&self, p: Point) -> &T {
+        match self.index_of(p) {
+            Some(i) => &self.cells[i],
+            None => panic!("{p:?} is outside the {}x{} grid", self.width, self.height),
+        }
     }
 }
 
-impl FromStr for Grid<char> {
-    type Err = GridError;
+impl<T: Copy + Debug + PartialEq + Eq> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        match self.index_of(p) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{p:?} is outside the {}x{} grid", self.width, self.height),
+        }
+    }
+}
 
-    fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let mut rows: Vec<Vec<char>> = Vec::new();
-        rows.extend(input.split('\n').map(|line| {
-            let line = line.trim_matches(|c| c == '\n' || c == '\r');
-            let mut row: Vec<char> = Vec::new();
-            row.extend(line.chars());
-            row
-        }));
-        Grid::new(rows)
+impl<T: GridCell> Grid<T> {
+    /// Parses one row per line, one cell per character. Lines may end in CRLF, and empty
+    /// lines at the end are ignored; a trailing row of spaces is kept.
+    pub fn parse(input: &str) -> Result<Self, GridError> {
+        let mut lines: Vec<&str> = input
+            .split('\n')
+            .map(|line| line.strip_suffix('\r').unwrap_or(line))
+            .collect();
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        let mut cells: Vec<T> = Vec::with_capacity(width * lines.len());
+        for (row, line) in lines.iter().enumerate() {
+            let start = cells.len();
+            for (col, c) in line.chars().enumerate() {
+                let cell = T::from_char(c).ok_or(GridError::InvalidCell { row, col, value: c })?;
+                cells.push(cell);
+            }
+            if cells.len() - start != width {
+                return Err(GridError::MismatchedColumns { row });
+            }
+        }
+        Ok(Self {
+            cells,
+            width,
+            height: lines.len(),
+        })
     }
 }
 
-impl FromStr for Grid<u32> {
+impl<T: GridCell> FromStr for Grid<T> {
     type Err = GridError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let mut rows: Vec<Vec<u32>> = Vec::new();
-        rows.extend(input.split('\n').map(|line| {
-            let mut row: Vec<u32> = Vec::new();
-            row.extend(
-                line.chars()
-                    .map(|c| c.to_digit(10).expect("Failed to read digit")),
-            );
-            row
-        }));
-        Grid::new(rows)
+        Grid::parse(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::grid::{Grid, GridCell, GridError, Neighbourhood, Point};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        Floor,
+        Wall,
+    }
+
+    impl GridCell for Tile {
+        fn from_char(c: char) -> Option<Self> {
+            match c {
+                '.' => Some(Tile::Floor),
+                '#' => Some(Tile::Wall),
+                _ => None,
+            }
+        }
+
+        fn to_char(&self) -> char {
+            match self {
+                Tile::Floor => '.',
+                Tile::Wall => '#',
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_cell_type() {
+        let grid = Grid::<Tile>::parse("#.#\r\n..#\r\n").unwrap();
+        assert_eq!((2, 3), (grid.rows(), grid.cols()));
+        assert_eq!(Some(Tile::Wall), grid.get(Point::new(2, 1)));
+        assert_eq!("#.#\n..#\n", grid.to_string());
+    }
+
+    #[test]
+    fn test_parse_trailing_blank_lines() {
+        let grid: Grid<u32> = "12\n34\n\n\n".parse().unwrap();
+        assert_eq!((2, 2), (grid.rows(), grid.cols()));
+        assert_eq!("12\n34\n", grid.to_string());
+        assert_eq!(0, Grid::<char>::parse("\n").unwrap().rows());
+
+        let grid = Grid::<char>::parse("#.\r\n  \r\n\r\n").unwrap();
+        assert_eq!((2, 2), (grid.rows(), grid.cols()));
+        assert_eq!(' ', grid[Point::new(1, 1)]);
+    }
+
+    #[test]
+    fn test_index_and_points() {
+        let mut grid = Grid::<u32>::parse("123\n456\n").unwrap();
+        assert_eq!(5, grid[Point::new(1, 1)]);
+        assert_eq!(Some(5), grid.index_of(Point::new(2, 1)));
+        assert_eq!(Point::new(2, 1), grid.point_of(5));
+        assert_eq!(None, grid.index_of(Point::new(-1, 0)));
+        assert_eq!(None, grid.get(Point::new(3, 0)));
+
+        grid[Point::new(0, 1)] = 9;
+        grid.swap(Point::new(0, 0), Point::new(0, 1)).unwrap();
+        assert_eq!(Some(Point::new(0, 0)), grid.search(9));
+        assert_eq!(
+            vec![1, 5, 6],
+            grid.walk_row(1).map(|(_, v)| v).collect::<Vec<_>>()
+        );
+        assert_eq!((Point::new(2, 1), 6), grid.walk().last().unwrap());
+        assert_eq!(3, grid.iter_adjacent(Point::new(0, 0)).count());
+        assert_eq!(Grid::filled(3, 2, false).to_string(), "...\n...\n");
+    }
+
+    #[test]
+    fn test_neighbourhoods() {
+        let grid = Grid::<u32>::parse("123\n456\n789").unwrap();
+        let values =
+            |n: Neighbourhood, p| grid.neighbours(p, n).map(|(_, v)| v).collect::<Vec<_>>();
+        let centre = Point::new(1, 1);
+        assert_eq!(vec![2, 6, 8, 4], values(Neighbourhood::VonNeumann, centre));
+        assert_eq!(
+            vec![2, 3, 6, 9, 8, 7, 4, 1],
+            values(Neighbourhood::Moore, centre)
+        );
+        assert_eq!(vec![3, 9, 7, 1], values(Neighbourhood::Diagonal, centre));
+
+        let knight = [Point::new(1, 2), Point::new(2, 1), Point::new(-1, -2)];
+        assert_eq!(
+            vec![8, 6],
+            values(Neighbourhood::Custom(&knight), Point::new(0, 0))
+        );
+
+        assert_eq!(
+            2,
+            grid.count_neighbours(Point::new(0, 0), Neighbourhood::Moore, |v| v % 2 == 0)
+        );
+        assert_eq!(
+            vec![Point::new(1, 0), Point::new(1, 1)],
+            grid.neighbours_matching(Point::new(0, 0), Neighbourhood::Moore, |v| v % 2 == 1
+                || v == 2)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_neighbours_wrapping() {
+        let grid = Grid::<u32>::parse("123\n456\n789").unwrap();
+        let corner: Vec<(Point, u32)> = grid
+            .neighbours_wrapping(Point::new(0, 0), Neighbourhood::VonNeumann)
+            .collect();
+        assert_eq!(
+            vec![
+                (Point::new(0, 2), 7),
+                (Point::new(1, 0), 2),
+                (Point::new(0, 1), 4),
+                (Point::new(2, 0), 3)
+            ],
+            corner
+        );
+        assert_eq!(
+            8,
+            grid.neighbours_wrapping(Point::new(2, 2), Neighbourhood::Moore)
+                .count()
+        );
+        let empty = Grid::<u32>::parse("").unwrap();
+        assert_eq!(
+            0,
+            empty
+                .neighbours_wrapping(Point::new(0, 0), Neighbourhood::Moore)
+                .count()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the 3x2 grid")]
+    fn test_index_outside() {
+        let grid = Grid::<u32>::parse("123\n456").unwrap();
+        let _ = grid[Point::new(0, 2)];
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Some(GridError::InvalidCell {
+                row: 1,
+                col: 2,
+                value: 'x'
+            }),
+            Grid::<Tile>::parse("...\n..x\n").err()
+        );
+        assert_eq!(
+            Some(GridError::MismatchedColumns { row: 1 }),
+            Grid::<bool>::parse("##\n#\n").err()
+        );
     }
 }
@@ -3,3 +3,5 @@ pub mod disjointset;
 pub mod grid;
 pub mod hashmap;
 pub mod parse;
+pub mod path;
+pub mod region;
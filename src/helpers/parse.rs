@@ -1,6 +1,8 @@
 use std::{fmt::Debug, str::FromStr};
 
-pub fn parse_lines<T>(input: &str) -> impl Iterator<Item = T>
+use crate::error::AocError;
+
+pub fn parse_lines<T>(input: &str) -> impl Iterator<Item = Result<T, AocError>>
 where
     T: FromStr,
     T::Err: Debug,
@@ -8,16 +10,17 @@ where
     parse_split(input.split('\n'))
 }
 
-pub fn parse_split<'a, T>(input: impl Iterator<Item = &'a str>) -> impl Iterator<Item = T>
+/// Parses each trimmed item, reporting failures against the item's position counted from 1.
+pub fn parse_split<'a, T>(
+    input: impl Iterator<Item = &'a str>,
+) -> impl Iterator<Item = Result<T, AocError>>
 where
     T: FromStr,
     T::Err: Debug,
 {
     input.enumerate().map(|(idx, x)| {
         let y = x.trim();
-        match y.parse::<T>() {
-            Ok(r) => r,
-            Err(e) => panic!("Error parsing line {idx} '{y}': {e:?}"),
-        }
+        y.parse::<T>()
+            .map_err(|e| AocError::parse_line(idx + 1, format!("'{y}': {e:?}")))
     })
 }
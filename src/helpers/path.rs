@@ -0,0 +1,432 @@
+//! Shortest paths over any search state, and over the cells of a [`Grid`].
+//!
+//! A state is whatever the search needs to tell positions apart: a [`Point`], or a point
+//! together with a heading or a run length when the moves allowed depend on how a cell
+//! was entered. Step costs should be positive; zero-cost cycles make the predecessor
+//! tree loop.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    fmt::Debug,
+    hash::Hash,
+};
+
+use crate::helpers::grid::{Grid, Neighbourhood, Point};
+
+/// Distances from the start states of a search, and every way each state is reached at
+/// its shortest distance.
+#[derive(Debug, Clone)]
+pub struct Paths<S: Copy + Eq + Hash> {
+    distances: HashMap<S, u64>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+impl<S: Copy + Eq + Hash> Paths<S> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goals: Vec::new(),
+        }
+    }
+
+    /// Records `from` as a way of reaching `to` at distance `d`, returning whether that
+    /// is shorter than any way found so far.
+    fn relax(&mut self, from: S, to: S, d: u64) -> bool {
+        match self.distances.get(&to) {
+            Some(&known) if d > known => false,
+            Some(&known) if d == known => {
+                let preds = self.predecessors.entry(to).or_default();
+                if to != from && !preds.contains(&from) {
+                    preds.push(from);
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(to, d);
+                self.predecessors.insert(to, vec![from]);
+                true
+            }
+        }
+    }
+
+    /// The shortest distance to `s`, `None` if it wasn't reached.
+    pub fn distance(&self, s: S) -> Option<u64> {
+        self.distances.get(&s).copied()
+    }
+
+    /// Every state reached, with its shortest distance.
+    pub fn distances(&self) -> &HashMap<S, u64> {
+        &self.distances
+    }
+
+    /// The states one step before `s` on its shortest paths; empty for a start state.
+    pub fn predecessors(&self, s: S) -> &[S] {
+        self.predecessors.get(&s).map_or(&[], Vec::as_slice)
+    }
+
+    /// The goal states a targeted search stopped at, all at the same shortest distance.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// One shortest path from a start state to `s`, both included.
+    pub fn path_to(&self, s: S) -> Option<Vec<S>> {
+        self.distances.get(&s)?;
+        let mut path = vec![s];
+        while let Some(&prev) = self.predecessors(*path.last().unwrap()).first() {
+            path.push(prev);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every shortest path from a start state to `s`. There can be exponentially many;
+    /// [`Paths::on_paths_to`] is cheaper when only the states on them matter.
+    pub fn all_paths_to(&self, s: S) -> Vec<Vec<S>> {
+        if !self.distances.contains_key(&s) {
+            return Vec::new();
+        }
+        let preds = self.predecessors(s);
+        if preds.is_empty() {
+            return vec![vec![s]];
+        }
+        let mut paths = Vec::new();
+        for &prev in preds {
+            for mut path in self.all_paths_to(prev) {
+                path.push(s);
+                paths.push(path);
+            }
+        }
+        paths
+    }
+
+    /// The states that lie on any shortest path to any of `targets`.
+    pub fn on_paths_to(&self, targets: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<S> = targets
+            .into_iter()
+            .filter(|t| self.distances.contains_key(t))
+            .collect();
+        while let Some(s) = stack.pop() {
+            if seen.insert(s) {
+                stack.extend_from_slice(self.predecessors(s));
+            }
+        }
+        seen
+    }
+}
+
+impl Paths<Point> {
+    /// The distances as a `cols` by `rows` grid, `None` for cells that weren't reached.
+    pub fn distance_grid(&self, cols: usize, rows: usize) -> Grid<Option<u64>> {
+        let mut grid = Grid::filled(cols, rows, None);
+        for (&p, &d) in &self.distances {
+            if grid.contains(p) {
+                grid[p] = Some(d);
+            }
+        }
+        grid
+    }
+}
+
+/// Breadth-first search where every step costs 1, exploring everything reachable from
+/// `starts`.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(S) -> I,
+) -> Paths<S>
+where
+    S: Copy + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for s in starts {
+        if paths.distances.insert(s, 0).is_none() {
+            queue.push_back(s);
+        }
+    }
+    while let Some(s) = queue.pop_front() {
+        let d = paths.distances[&s] + 1;
+        for next in successors(s) {
+            if paths.relax(s, next, d) {
+                queue.push_back(next);
+            }
+        }
+    }
+    paths
+}
+
+/// Dijkstra's algorithm with `successors` giving each next state and the cost of the
+/// step, exploring everything reachable from `starts`.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(S) -> I,
+) -> Paths<S>
+where
+    S: Copy + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(starts, successors, |_| 0, |_| false)
+}
+
+/// A* search from `starts` that stops once the shortest distance to a state satisfying
+/// `is_goal` is known, recording every goal state at that distance.
+///
+/// `heuristic` must never overestimate the remaining distance to a goal and should not
+/// drop by more than a step's cost from one state to the next; a heuristic of 0 makes
+/// this Dijkstra's algorithm.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(S) -> I,
+    heuristic: impl Fn(S) -> u64,
+    is_goal: impl Fn(S) -> bool,
+) -> Paths<S>
+where
+    S: Copy + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut paths = Paths::new();
+    let mut open = BinaryHeap::new();
+    for s in starts {
+        if paths.distances.insert(s, 0).is_none() {
+            open.push(Open::new(s, 0, heuristic(s)));
+        }
+    }
+
+    let mut best: Option<u64> = None;
+    while let Some(Open {
+        estimate,
+        cost,
+        state,
+    }) = open.pop()
+    {
+        // Past the goals' distance nothing can reach another goal as cheaply.
+        if best.is_some_and(|b| estimate > b) {
+            break;
+        }
+        if cost > paths.distances[&state] {
+            continue;
+        }
+        if is_goal(state) {
+            best = Some(cost);
+            paths.goals.push(state);
+            continue;
+        }
+        for (next, step) in successors(state) {
+            let d = cost + step;
+            if paths.relax(state, next, d) {
+                open.push(Open::new(next, d, heuristic(next)));
+            }
+        }
+    }
+    paths
+}
+
+/// A state waiting in the A* open set, popped lowest estimate first.
+struct Open<S> {
+    estimate: u64,
+    cost: u64,
+    state: S,
+}
+
+impl<S> Open<S> {
+    fn new(state: S, cost: u64, heuristic: u64) -> Self {
+        Self {
+            estimate: cost + heuristic,
+            cost,
+            state,
+        }
+    }
+}
+
+impl<S> PartialEq for Open<S> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.estimate, self.cost) == (other.estimate, other.cost)
+    }
+}
+
+impl<S> Eq for Open<S> {}
+
+impl<S> PartialOrd for Open<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> Ord for Open<S> {
+    /// Reversed, since [`BinaryHeap`] pops the greatest. Ties go to the state furthest
+    /// along, which tends to reach the goal sooner.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then(self.cost.cmp(&other.cost))
+    }
+}
+
+impl<T: Copy + Debug + PartialEq + Eq> Grid<T> {
+    /// Steps between orthogonal neighbours from `start` to every cell reachable through
+    /// cells whose value is `passable`.
+    pub fn bfs(&self, start: Point, passable: impl Fn(T) -> bool) -> Paths<Point> {
+        bfs([start], |p| {
+            self.neighbours_matching(p, Neighbourhood::VonNeumann, &passable)
+        })
+    }
+
+    /// Least total cost from `start` to every reachable cell, moving between orthogonal
+    /// neighbours. `cost` is given the values of the cell left and the cell entered, and
+    /// returns `None` if the step isn't allowed.
+    pub fn dijkstra(&self, start: Point, cost: impl Fn(T, T) -> Option<u64>) -> Paths<Point> {
+        dijkstra([start], |p| self.steps(p, &cost))
+    }
+
+    /// The cheapest path from `start` to `goal`, both included, and its cost, with
+    /// `cost` as for [`Grid::dijkstra`]. Guided by the Manhattan distance, so every step
+    /// must cost at least 1.
+    pub fn shortest_path(
+        &self,
+        start: Point,
+        goal: Point,
+        cost: impl Fn(T, T) -> Option<u64>,
+    ) -> Option<(u64, Vec<Point>)> {
+        self.shortest_path_with(start, goal, cost, |p| p.manhattan(goal))
+    }
+
+    /// [`Grid::shortest_path`] guided by `heuristic` instead, see [`astar`].
+    pub fn shortest_path_with(
+        &self,
+        start: Point,
+        goal: Point,
+        cost: impl Fn(T, T) -> Option<u64>,
+        heuristic: impl Fn(Point) -> u64,
+    ) -> Option<(u64, Vec<Point>)> {
+        let paths = astar([start], |p| self.steps(p, &cost), heuristic, |p| p == goal);
+        Some((paths.distance(goal)?, paths.path_to(goal)?))
+    }
+
+    /// The allowed steps to the orthogonal neighbours of `p` and their costs, none if `p`
+    /// is outside the grid.
+    fn steps<'a>(
+        &'a self,
+        p: Point,
+        cost: &'a impl Fn(T, T) -> Option<u64>,
+    ) -> impl Iterator<Item = (Point, u64)> + 'a {
+        let from = self.get(p);
+        self.neighbours(p, Neighbourhood::VonNeumann)
+            .filter_map(move |(q, to)| cost(from?, to).map(|c| (q, c)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::helpers::{
+        grid::{Grid, Point},
+        path::{Paths, astar, dijkstra},
+    };
+
+    const MAZE: &str = "\
+#######
+#S..#.#
+#.#.#.#
+#...#E#
+#######";
+
+    fn maze() -> (Grid<char>, Point, Point) {
+        let grid = Grid::<char>::parse(MAZE).unwrap();
+        let (start, end) = (grid.search('S').unwrap(), grid.search('E').unwrap());
+        (grid, start, end)
+    }
+
+    #[test]
+    fn test_bfs() {
+        let (grid, start, end) = maze();
+        let paths = grid.bfs(start, |v| v != '#');
+        assert_eq!(Some(4), paths.distance(Point::new(3, 3)));
+        assert_eq!(None, paths.distance(end));
+        assert_eq!(8, paths.distances().len());
+
+        // Two ways round the pillar to the far corner.
+        let corner = Point::new(3, 3);
+        assert_eq!(2, paths.predecessors(corner).len());
+        assert_eq!(2, paths.all_paths_to(corner).len());
+        assert_eq!(8, paths.on_paths_to([corner]).len());
+        let path = paths.path_to(corner).unwrap();
+        assert_eq!((start, corner, 5), (path[0], path[4], path.len()));
+
+        let distances = paths.distance_grid(grid.cols(), grid.rows());
+        assert_eq!(Some(0), distances[start]);
+        assert_eq!(Some(2), distances[Point::new(1, 3)]);
+        assert_eq!(None, distances[Point::new(0, 0)]);
+    }
+
+    #[test]
+    fn test_weighted() {
+        let grid = Grid::<u32>::parse("1163\n1381\n2136").unwrap();
+        let (start, goal) = (Point::new(0, 0), Point::new(3, 2));
+        let enter = |_, to: u32| Some(to as u64);
+
+        let paths = grid.dijkstra(start, enter);
+        assert_eq!(Some(13), paths.distance(goal));
+
+        let (cost, path) = grid.shortest_path(start, goal, enter).unwrap();
+        assert_eq!(13, cost);
+        assert_eq!((start, goal), (path[0], *path.last().unwrap()));
+        let total: u32 = path[1..].iter().map(|p| grid[*p]).sum();
+        assert_eq!(13, total);
+
+        let (cost, _) = grid.shortest_path_with(start, goal, enter, |_| 0).unwrap();
+        assert_eq!(13, cost);
+        let walls = |_, to: u32| (to < 6).then_some(1);
+        assert_eq!(None, grid.shortest_path(start, Point::new(2, 0), walls));
+
+        let outside = Point::new(-1, 0);
+        assert_eq!(None, grid.shortest_path(outside, goal, enter));
+        assert_eq!(None, grid.dijkstra(outside, enter).distance(start));
+    }
+
+    /// A reindeer that pays 1 to step forward and 1000 to turn, so the state is its
+    /// position and heading. It may set off east or north.
+    fn reindeer(grid: &Grid<char>, start: Point) -> Paths<(Point, Point)> {
+        let turn = |d: Point| [Point::new(-d.y, d.x), Point::new(d.y, -d.x)];
+        let moves = |(p, d): (Point, Point)| {
+            let forward = (grid[p + d] != '#').then_some(((p + d, d), 1));
+            forward.into_iter().chain(turn(d).map(|t| ((p, t), 1000)))
+        };
+        let end = grid.search('E').unwrap();
+        astar(
+            [(start, Point::new(1, 0)), (start, Point::new(0, -1))],
+            moves,
+            |(p, _)| p.manhattan(end),
+            |(p, _)| p == end,
+        )
+    }
+
+    #[test]
+    fn test_state_with_heading() {
+        let grid = Grid::<char>::parse("#####\n#..E#\n#.#.#\n#S..#\n#####").unwrap();
+        let start = grid.search('S').unwrap();
+        let paths = reindeer(&grid, start);
+
+        let goals = paths.goals();
+        assert_eq!(2, goals.len());
+        assert_eq!(Some(1004), paths.distance(goals[0]));
+        assert_eq!(Some(1004), paths.distance(goals[1]));
+        let tiles: HashSet<Point> = paths
+            .on_paths_to(goals.iter().copied())
+            .into_iter()
+            .map(|(p, _)| p)
+            .collect();
+        assert_eq!(8, tiles.len());
+
+        let all = dijkstra([(start, Point::new(1, 0))], |(p, d): (Point, Point)| {
+            (grid[p + d] != '#').then_some(((p + d, d), 1))
+        });
+        assert_eq!(3, all.distances().len());
+    }
+}
@@ -0,0 +1,198 @@
+//! Connected regions of a [`Grid`] and flood fill.
+
+use std::fmt::Debug;
+
+use crate::helpers::grid::{Grid, Neighbourhood, Point};
+
+/// A connected set of cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region<T> {
+    /// The value of the region's first cell in reading order.
+    pub value: T,
+    /// The cells in reading order.
+    pub cells: Vec<Point>,
+    /// Cell edges that don't border another cell of the region.
+    pub perimeter: usize,
+    /// Straight runs of the boundary, which is also the number of corners.
+    pub sides: usize,
+}
+
+impl<T> Region<T> {
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle holding every cell.
+    pub fn bounding_box(&self) -> (Point, Point) {
+        self.cells.iter().fold(
+            (
+                Point::new(i64::MAX, i64::MAX),
+                Point::new(i64::MIN, i64::MIN),
+            ),
+            |(min, max), p| {
+                (
+                    Point::new(min.x.min(p.x), min.y.min(p.y)),
+                    Point::new(max.x.max(p.x), max.y.max(p.y)),
+                )
+            },
+        )
+    }
+}
+
+impl<T: Copy + Debug + PartialEq + Eq> Grid<T> {
+    /// The cells reachable from `start` through orthogonal neighbours whose value satisfies
+    /// `predicate`, `start` first. Empty if `start` itself doesn't satisfy it.
+    pub fn flood_fill(&self, start: Point, predicate: impl Fn(T) -> bool) -> Vec<Point> {
+        let mut seen = Grid::filled(self.cols(), self.rows(), false);
+        self.fill(start, Neighbourhood::VonNeumann, &predicate, &mut seen)
+    }
+
+    /// Every region of equal cells connected through the neighbourhood `n`, in reading
+    /// order of their first cells.
+    pub fn regions(&self, n: Neighbourhood<'_>) -> Vec<Region<T>> {
+        self.label(n, |_| true, |a, b| a == b)
+    }
+
+    /// Every region of cells satisfying `predicate` connected through the neighbourhood
+    /// `n`, whatever their values; other cells belong to no region.
+    pub fn regions_where(
+        &self,
+        n: Neighbourhood<'_>,
+        predicate: impl Fn(T) -> bool,
+    ) -> Vec<Region<T>> {
+        self.label(n, &predicate, |_, b| predicate(b))
+    }
+
+    /// Grows a region from each cell not yet in one that `include` accepts, through
+    /// neighbours that `joins` accepts given the first cell's value.
+    fn label(
+        &self,
+        n: Neighbourhood<'_>,
+        include: impl Fn(T) -> bool,
+        joins: impl Fn(T, T) -> bool,
+    ) -> Vec<Region<T>> {
+        let mut seen = Grid::filled(self.cols(), self.rows(), false);
+        let mut labels: Grid<Option<usize>> = Grid::filled(self.cols(), self.rows(), None);
+        let mut regions = Vec::new();
+        for (p, value) in self.walk() {
+            if seen[p] || !include(value) {
+                continue;
+            }
+            let mut cells = self.fill(p, n, &|v| joins(value, v), &mut seen);
+            cells.sort_by_key(|q| (q.y, q.x));
+            let id = Some(regions.len());
+            for &q in &cells {
+                labels[q] = id;
+            }
+
+            let inside = |q: Point| labels.get(q) == Some(id);
+            let orthogonal = Neighbourhood::VonNeumann.offsets();
+            let mut perimeter = 0;
+            let mut sides = 0;
+            for &q in &cells {
+                for (i, &a) in orthogonal.iter().enumerate() {
+                    let b = orthogonal[(i + 1) % orthogonal.len()];
+                    let (in_a, in_b) = (inside(q + a), inside(q + b));
+                    perimeter += usize::from(!in_a);
+                    // An outside corner, or an inside one where the diagonal cell is missing.
+                    if (!in_a && !in_b) || (in_a && in_b && !inside(q + a + b)) {
+                        sides += 1;
+                    }
+                }
+            }
+
+            regions.push(Region {
+                value,
+                cells,
+                perimeter,
+                sides,
+            });
+        }
+        regions
+    }
+
+    fn fill(
+        &self,
+        start: Point,
+        n: Neighbourhood<'_>,
+        predicate: &impl Fn(T) -> bool,
+        seen: &mut Grid<bool>,
+    ) -> Vec<Point> {
+        if !self.get(start).is_some_and(predicate) || seen[start] {
+            return Vec::new();
+        }
+        seen[start] = true;
+        let mut cells = vec![start];
+        let mut next = 0;
+        while let Some(&p) = cells.get(next) {
+            next += 1;
+            for q in self.neighbours_matching(p, n, predicate) {
+                if !seen[q] {
+                    seen[q] = true;
+                    cells.push(q);
+                }
+            }
+        }
+        cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::grid::{Grid, Neighbourhood, Point};
+
+    const GARDEN: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+    #[test]
+    fn test_regions() {
+        let grid = Grid::<char>::parse(GARDEN).unwrap();
+        let regions = grid.regions(Neighbourhood::VonNeumann);
+        assert_eq!(11, regions.len());
+        let price: usize = regions.iter().map(|r| r.area() * r.perimeter).sum();
+        assert_eq!(1930, price);
+        let discount: usize = regions.iter().map(|r| r.area() * r.sides).sum();
+        assert_eq!(1206, discount);
+
+        let r = &regions[0];
+        assert_eq!(('R', 12, 18, 10), (r.value, r.area(), r.perimeter, r.sides));
+        assert_eq!((Point::new(0, 0), Point::new(4, 3)), r.bounding_box());
+    }
+
+    #[test]
+    fn test_regions_where() {
+        let grid = Grid::<char>::parse("#..#\n.#..\n...#").unwrap();
+        let wall = |v| v == '#';
+        assert_eq!(4, grid.regions_where(Neighbourhood::VonNeumann, wall).len());
+
+        let joined = grid.regions_where(Neighbourhood::Moore, wall);
+        assert_eq!(3, joined.len());
+        assert_eq!(vec![Point::new(0, 0), Point::new(1, 1)], joined[0].cells);
+        assert_eq!((8, 8), (joined[0].perimeter, joined[0].sides));
+        let floor = grid.regions_where(Neighbourhood::Moore, |v| v == '.');
+        assert_eq!((1, 8), (floor.len(), floor[0].area()));
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = Grid::<char>::parse("..#\n.##\n#..").unwrap();
+        let open = |v| v == '.';
+        let filled = grid.flood_fill(Point::new(0, 0), open);
+        assert_eq!(
+            vec![Point::new(0, 0), Point::new(1, 0), Point::new(0, 1)],
+            filled
+        );
+        assert_eq!(2, grid.flood_fill(Point::new(2, 2), open).len());
+        assert!(grid.flood_fill(Point::new(2, 0), open).is_empty());
+        assert!(grid.flood_fill(Point::new(5, 5), open).is_empty());
+    }
+}
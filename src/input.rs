@@ -0,0 +1,38 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::error::{AocError, Result};
+
+pub const DEFAULT_INPUTS_DIR: &str = "inputs";
+
+pub fn input_path(dir: &Path, year: u16, day: u32) -> PathBuf {
+    dir.join(year.to_string()).join(format!("day{day}.txt"))
+}
+
+/// Reads a puzzle input from `path`, or from stdin if `path` is `-`.
+///
+/// Trailing line breaks are stripped so that downloaded inputs parse the same
+/// as hand-trimmed ones.
+pub fn read_input(path: &Path) -> Result<String> {
+    let mut input = String::new();
+    if path == Path::new("-") {
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| AocError::Io(path.to_path_buf(), e))?;
+    } else {
+        input = match fs::read_to_string(path) {
+            Ok(s) => s,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                return Err(AocError::MissingInput(path.to_path_buf()));
+            }
+            Err(e) => return Err(AocError::Io(path.to_path_buf(), e)),
+        };
+    }
+
+    let len = input.trim_end_matches(['\n', '\r']).len();
+    input.truncate(len);
+    Ok(input)
+}
@@ -0,0 +1,253 @@
+use std::{
+    any::Any,
+    fmt,
+    sync::{
+        Arc,
+        mpsc::{self, RecvTimeoutError},
+    },
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    answer::Answer,
+    solver::{Context, DynSolver, Timings},
+    status::PartStatus,
+};
+
+/// Per-part time limit of a normal run.
+pub const DEFAULT_TIMEOUT_SECS: u64 = 10;
+
+/// The limit for a `--timeout` of `secs` seconds, where 0 means none.
+pub fn time_limit(secs: u64) -> Option<Duration> {
+    (secs > 0).then(|| Duration::from_secs(secs))
+}
+
+/// Why a part produced no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartFailure {
+    /// The solver, or the parser before it, returned this error.
+    Error(String),
+    /// The part panicked with this message.
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl PartFailure {
+    pub fn status(&self) -> PartStatus {
+        match self {
+            PartFailure::Error(_) => PartStatus::Failed,
+            PartFailure::Panicked(_) => PartStatus::Panicked,
+            PartFailure::TimedOut(_) => PartStatus::TimedOut,
+        }
+    }
+}
+
+impl fmt::Display for PartFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PartFailure::Error(message) => write!(f, "{message}"),
+            PartFailure::Panicked(message) => write!(f, "panicked: {message}"),
+            PartFailure::TimedOut(limit) => write!(f, "timed out after {limit:.2?}"),
+        }
+    }
+}
+
+/// The message a panic was raised with, as passed to `panic!`.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+/// Answers and timings of a run in which each part was kept from failing the other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IsolatedRun {
+    /// [`Answer::NotImplemented`] for a part that wasn't asked for.
+    pub parts: [Result<Answer, PartFailure>; 2],
+    /// A part that ran out of time is counted at the limit.
+    pub timings: Timings,
+}
+
+/// Runs the parts `ctx` selects one after the other, each on a worker thread of its own
+/// that parses the input and solves, so that an error or panic only fails that part.
+///
+/// With a `limit`, a part that runs longer has its cancellation token set and is reported
+/// as timed out; a solver that never checks the token keeps its thread busy in the
+/// background until the process exits.
+pub fn run_isolated(
+    solver: &'static dyn DynSolver,
+    input: &str,
+    ctx: &Context,
+    limit: Option<Duration>,
+) -> IsolatedRun {
+    let input: Arc<str> = Arc::from(input);
+    let mut run = IsolatedRun {
+        parts: [Ok(Answer::NotImplemented), Ok(Answer::NotImplemented)],
+        timings: Timings::default(),
+    };
+    let mut parsed = false;
+
+    for part in (1..=2).filter(|&p| ctx.runs_part(p)) {
+        let ctx = ctx.for_part(part);
+        let (tx, rx) = mpsc::channel();
+        let worker = {
+            let (input, ctx) = (Arc::clone(&input), ctx.clone());
+            thread::spawn(move || {
+                // The receiver is gone if the part ran out of time.
+                let _ = tx.send(solver.run_timed(&input, &ctx));
+            })
+        };
+
+        let received = match limit {
+            Some(limit) => rx.recv_timeout(limit),
+            None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        let (outcome, time) = match received {
+            Ok(Ok((a, b, timings))) => {
+                if !parsed {
+                    run.timings.parse = timings.parse;
+                    run.timings.parse_mem = timings.parse_mem;
+                    parsed = true;
+                }
+                if part == 1 {
+                    run.timings.part1_mem = timings.part1_mem;
+                    (Ok(a), timings.part1)
+                } else {
+                    run.timings.part2_mem = timings.part2_mem;
+                    (Ok(b), timings.part2)
+                }
+            }
+            Ok(Err(e)) => (Err(PartFailure::Error(e.to_string())), Duration::ZERO),
+            Err(RecvTimeoutError::Timeout) => {
+                ctx.cancel_token().cancel();
+                let limit = limit.expect("only a limited wait times out");
+                (Err(PartFailure::TimedOut(limit)), limit)
+            }
+            Err(RecvTimeoutError::Disconnected) => match worker.join() {
+                Err(payload) => (
+                    Err(PartFailure::Panicked(panic_message(&*payload))),
+                    Duration::ZERO,
+                ),
+                Ok(()) => unreachable!("the worker always sends a result unless it panics"),
+            },
+        };
+
+        run.parts[part as usize - 1] = outcome;
+        if part == 1 {
+            run.timings.part1 = time;
+        } else {
+            run.timings.part2 = time;
+        }
+    }
+    run
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicBool, Ordering},
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use crate::{
+        answer::Answer,
+        error::AocError,
+        isolate::{PartFailure, run_isolated},
+        solver::{Context, DynSolver, Solver, find},
+        status::PartStatus,
+    };
+
+    static STOPPED: AtomicBool = AtomicBool::new(false);
+
+    /// Part 1 runs until cancelled unless the input is "fail", part 2 panics on "panic".
+    struct Spin;
+
+    impl Solver for Spin {
+        const YEAR: u16 = 2015;
+        const DAY: u32 = 1;
+
+        type Input<'a> = &'a str;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+            Ok(input)
+        }
+
+        fn part1(input: &Self::Input<'_>, ctx: &Context) -> Result<Answer, AocError> {
+            if *input == "fail" {
+                return Err(AocError::solver("no luck"));
+            }
+            loop {
+                if let Err(e) = ctx.check_cancelled() {
+                    STOPPED.store(true, Ordering::Relaxed);
+                    return Err(e);
+                }
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+
+        fn part2(input: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+            if *input == "panic" {
+                panic!("boom at {}", 7);
+            }
+            Ok(Answer::Integer(2))
+        }
+    }
+
+    static SPIN: &dyn DynSolver = &Spin;
+
+    #[test]
+    fn test_within_limit() {
+        let solver = find(2025, 3).unwrap();
+        let ctx = Context::new(solver.params(), &[], None).unwrap();
+        let limit = Some(Duration::from_secs(10));
+        let run = run_isolated(solver, "987654321111111", &ctx, limit);
+        assert_eq!(
+            [Ok(Answer::Integer(98)), Ok(Answer::Integer(987654321111))],
+            run.parts
+        );
+    }
+
+    #[test]
+    fn test_timed_out_part_is_cancelled() {
+        let limit = Duration::from_millis(50);
+        let start = Instant::now();
+        let run = run_isolated(SPIN, "", &Context::default(), Some(limit));
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert_eq!(
+            [Err(PartFailure::TimedOut(limit)), Ok(Answer::Integer(2))],
+            run.parts
+        );
+        assert_eq!(limit, run.timings.part1);
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !STOPPED.load(Ordering::Relaxed) && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(1));
+        }
+        assert!(STOPPED.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn test_failures_stay_in_their_part() {
+        let run = run_isolated(SPIN, "fail", &Context::default(), None);
+        assert_eq!(
+            [
+                Err(PartFailure::Error("solver failed: no luck".to_string())),
+                Ok(Answer::Integer(2))
+            ],
+            run.parts
+        );
+
+        let ctx = Context::new(&[], &[], Some(2)).unwrap();
+        let run = run_isolated(SPIN, "panic", &ctx, None);
+        let failure = PartFailure::Panicked("boom at 7".to_string());
+        assert_eq!(PartStatus::Panicked, failure.status());
+        assert_eq!("panicked: boom at 7", failure.to_string());
+        assert_eq!([Ok(Answer::NotImplemented), Err(failure)], run.parts);
+    }
+}
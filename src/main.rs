@@ -1,60 +1,807 @@
-use std::{fs::File, io::Write};
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::RangeInclusive,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::Duration,
+};
 
-use clap::{Parser, command};
+use clap::{Parser, Subcommand};
 
+use crate::{
+    answer::Answer,
+    bench::{DayBench, Summary, bench},
+    error::{AocError, Result},
+    example::{Example, example_path, example_paths},
+    fetch::{Client, DEFAULT_BASE_URL, Fetched, fetch_input, session_token},
+    input::{DEFAULT_INPUTS_DIR, input_path, read_input},
+    isolate::{
+        DEFAULT_TIMEOUT_SECS, IsolatedRun, PartFailure, panic_message, run_isolated, time_limit,
+    },
+    mem::MemTable,
+    pool::{for_each_ordered, worker_count},
+    report::{Format, PartRecord, render},
+    scaffold::{DEFAULT_SRC_DIR, create_day, create_example},
+    solutions::{SolutionStatus, Solutions, read_solutions, solutions_path, write_solutions},
+    solver::{Context, DynSolver, Timings},
+    status::{PartStatus, StatusMatrix},
+    submit::{Verdict, history_path, submit},
+    verify::{Mismatch, MismatchTable, verify_day},
+    watch::{DEFAULT_INTERVAL_MS, Watcher, watched_files},
+};
+
+pub mod answer;
+pub mod bench;
+pub mod error;
+pub mod example;
+pub mod fetch;
 pub mod helpers;
+pub mod input;
+pub mod isolate;
+pub mod mem;
+pub mod pool;
+pub mod report;
+pub mod scaffold;
+pub mod solutions;
+pub mod solver;
+pub mod status;
+pub mod submit;
+pub mod verify;
+pub mod watch;
 pub mod y2024;
 pub mod y2025;
 
 #[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
+#[command(name = "aoc", version, about, long_about = None, args_conflicts_with_subcommands = true)]
 struct Args {
-    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2024..2026), default_value_t = 2025)]
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Year or inclusive range of years with solvers, e.g. 2025, 2024-2025 or all.
+    /// Without --day this defaults to every year with a solver, with --day to the latest
+    /// year with a stored answer
+    #[arg(short, long, value_parser = parse_years)]
+    year: Option<RangeInclusive<u16>>,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..26))]
+    day: Option<u16>,
+
+    /// Store computed answers as solved for parts that have no stored answer yet
+    #[arg(short, long, conflicts_with_all = ["part", "example", "input", "params"])]
+    build_solution_file: bool,
+
+    /// Only run this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Override a value the day's solver declares, e.g. `connections=10`
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param, requires = "day")]
+    params: Vec<(String, String)>,
+
+    /// Read the puzzle input from this file instead of the inputs directory ("-" for stdin)
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+
+    /// Run on the Nth example (1 if omitted) from <year>/day<N>.example.txt and check
+    /// its expected answers instead of the stored solutions
+    #[arg(short, long, value_name = "N", num_args = 0..=1, default_missing_value = "1",
+          conflicts_with = "input", value_parser = clap::value_parser!(u32).range(1..))]
+    example: Option<u32>,
+
+    /// Directory holding puzzle inputs as <year>/day<N>.txt
+    #[arg(long, default_value = DEFAULT_INPUTS_DIR)]
+    inputs_dir: PathBuf,
+
+    /// Time each day over N runs (10 if omitted) and report parse/part timings
+    #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "10",
+          value_parser = clap::value_parser!(u32).range(1..))]
+    bench: Option<u32>,
+
+    /// Report the peak heap use and number of allocations of each part. Needs a build
+    /// with `--features mem`
+    #[arg(long, conflicts_with = "bench")]
+    mem: bool,
+
+    /// How to print results; everything but text is one row per part
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Give up on a part after SECS seconds, 0 for no limit. A benchmark stops at the
+    /// first run with a part that times out
+    #[arg(long, value_name = "SECS", default_value_t = DEFAULT_TIMEOUT_SECS)]
+    timeout: u64,
+
+    /// Run up to N days at once, capped at the number of cores. Results are still
+    /// reported in year/day order
+    #[arg(short, long, value_name = "N", default_value_t = 1)]
+    jobs: usize,
+}
+
+/// Parses a year, a range of years or `all`, accepting only years that have solvers.
+fn parse_years(s: &str) -> std::result::Result<RangeInclusive<u16>, String> {
+    let registered = solver::years();
+    if s.trim() == "all" {
+        return match (registered.first(), registered.last()) {
+            (Some(&first), Some(&last)) => Ok(first..=last),
+            _ => Err("no year has a solver yet".to_string()),
+        };
+    }
+    let year = |y: &str| match y.trim().parse::<u16>() {
+        Ok(y) if registered.contains(&y) => Ok(y),
+        _ => Err(format!(
+            "no solvers for '{y}'; expected one of {} or all",
+            registered
+                .iter()
+                .map(|y| y.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    };
+    let (start, end) = match s.split_once('-') {
+        Some((a, b)) => (year(a)?, year(b)?),
+        None => (year(s)?, year(s)?),
+    };
+    if start > end {
+        return Err(format!("{start} is after {end}"));
+    }
+    Ok(start..=end)
+}
+
+fn parse_param(s: &str) -> std::result::Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((k, v)) if !k.is_empty() => Ok((k.to_string(), v.to_string())),
+        _ => Err(format!("expected KEY=VALUE, got '{s}'")),
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Download a puzzle input into the inputs directory, unless it is already there
+    Fetch(FetchArgs),
+    /// Submit an answer, by default the one the solver computes
+    Submit(SubmitArgs),
+    /// Check every answer against the stored solutions, failing on any mismatch
+    Verify(VerifyArgs),
+    /// Create a template for a new day; it is registered on the next build
+    New(NewArgs),
+    /// Re-run a day on its examples and input whenever one of those files changes
+    Watch(WatchArgs),
+}
+
+#[derive(clap::Args, Debug)]
+struct FetchArgs {
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
     year: u16,
 
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..26))]
+    day: u32,
+
+    /// Directory holding puzzle inputs as <year>/day<N>.txt
+    #[arg(long, default_value = DEFAULT_INPUTS_DIR)]
+    inputs_dir: PathBuf,
+
+    /// Server to download from, e.g. a local stub when testing
+    #[arg(long, default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+#[derive(clap::Args, Debug)]
+struct SubmitArgs {
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: u16,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..26))]
+    day: u32,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Answer to send instead of running the solver
+    answer: Option<String>,
+
+    /// Give up on running the solver after SECS seconds, 0 for no limit
+    #[arg(long, value_name = "SECS", default_value_t = DEFAULT_TIMEOUT_SECS)]
+    timeout: u64,
+
+    /// Directory holding puzzle inputs and the guess history for each day
+    #[arg(long, default_value = DEFAULT_INPUTS_DIR)]
+    inputs_dir: PathBuf,
+
+    /// Server to submit to, e.g. a local stub when testing
+    #[arg(long, default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+#[derive(clap::Args, Debug)]
+struct VerifyArgs {
+    /// Year or inclusive range of years. Without --day this defaults to every year with a
+    /// solver, with --day to the latest year with a stored answer
+    #[arg(short, long, value_parser = parse_years)]
+    year: Option<RangeInclusive<u16>>,
+
     #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..26))]
     day: Option<u16>,
 
+    /// Directory holding puzzle inputs as <year>/day<N>.txt
+    #[arg(long, default_value = DEFAULT_INPUTS_DIR)]
+    inputs_dir: PathBuf,
+
+    /// Give up on a part after SECS seconds, 0 for no limit
+    #[arg(long, value_name = "SECS", default_value_t = DEFAULT_TIMEOUT_SECS)]
+    timeout: u64,
+}
+
+#[derive(clap::Args, Debug)]
+struct NewArgs {
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: u16,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..26))]
+    day: u32,
+
+    /// Source directory holding the y<year> modules
+    #[arg(long, default_value = DEFAULT_SRC_DIR)]
+    src_dir: PathBuf,
+
+    /// Directory holding puzzle inputs and examples
+    #[arg(long, default_value = DEFAULT_INPUTS_DIR)]
+    inputs_dir: PathBuf,
+}
+
+#[derive(clap::Args, Debug)]
+struct WatchArgs {
+    /// Defaults to the latest year with a stored answer
     #[arg(short, long)]
-    build_solution_file: bool,
+    year: Option<u16>,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..26))]
+    day: u32,
+
+    /// Directory holding puzzle inputs and examples
+    #[arg(long, default_value = DEFAULT_INPUTS_DIR)]
+    inputs_dir: PathBuf,
+
+    /// Milliseconds between checks for changes
+    #[arg(long, value_name = "MS", default_value_t = DEFAULT_INTERVAL_MS)]
+    interval: u64,
+
+    /// Give up on a part after SECS seconds, 0 for no limit
+    #[arg(long, value_name = "SECS", default_value_t = DEFAULT_TIMEOUT_SECS)]
+    timeout: u64,
+}
+
+fn new_day(args: &NewArgs) -> Result<()> {
+    let path = create_day(&args.src_dir, args.year, args.day)?;
+    println!("Created {}", path.display());
+    match create_example(&args.inputs_dir, args.year, args.day) {
+        Ok(path) => println!("Created {}", path.display()),
+        Err(AocError::AlreadyExists(path)) => println!("Kept {}", path.display()),
+        Err(e) => return Err(e),
+    }
+    Ok(())
+}
+
+/// The latest year with a stored answer, or failing that the latest year with a solver.
+fn default_year() -> Result<u16> {
+    let years = solver::years();
+    for &year in years.iter().rev() {
+        let solutions = read_solutions(year)?;
+        if solutions
+            .values()
+            .any(|(a, b)| a.is_implemented() || b.is_implemented())
+        {
+            return Ok(year);
+        }
+    }
+    years
+        .last()
+        .copied()
+        .ok_or_else(|| AocError::solver("no year has a solver yet"))
+}
+
+/// The years to cover when `year` isn't given: every year with a solver, or with an
+/// explicit `day` only the [`default_year`].
+fn select_years(
+    year: &Option<RangeInclusive<u16>>,
+    day: Option<u16>,
+) -> Result<RangeInclusive<u16>> {
+    match (year, day) {
+        (Some(years), _) => Ok(years.clone()),
+        (None, Some(_)) => default_year().map(|year| year..=year),
+        (None, None) => parse_years("all").map_err(AocError::solver),
+    }
 }
 
-fn main() {
+/// The registered days to run in `years`. An explicit `day` is run in every year that has
+/// it, and is only an error when a single year without it was asked for.
+fn select_days(years: &RangeInclusive<u16>, day: Option<u16>) -> Result<Vec<(u16, u32)>> {
+    let days: Vec<(u16, u32)> = solver::registry()
+        .map(|s| (s.year(), s.day()))
+        .filter(|(y, d)| years.contains(y) && day.is_none_or(|day| day as u32 == *d))
+        .collect();
+    match day {
+        Some(day) if days.is_empty() && years.start() == years.end() => Err(AocError::UnknownDay {
+            year: *years.start(),
+            day: day as u32,
+        }),
+        _ => Ok(days),
+    }
+}
+
+fn verify(args: &VerifyArgs) -> Result<bool> {
+    let years = select_years(&args.year, args.day)?;
+    let days = select_days(&years, args.day)?;
+    let mut mismatches: Vec<Mismatch> = Vec::new();
+    let (mut checked, mut without_input) = (0, 0);
+    for year in years {
+        let solutions = read_solutions(year)?;
+        for &(_, day) in days.iter().filter(|(y, _)| *y == year) {
+            let solver = solver::find(year, day).ok_or(AocError::UnknownDay { year, day })?;
+            let Some(expected) = solutions.get(&day) else {
+                continue;
+            };
+            let input = match read_input(&input_path(&args.inputs_dir, year, day)) {
+                Ok(input) => input,
+                Err(AocError::MissingInput(_)) => {
+                    without_input += 1;
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+            checked += 1;
+            let ctx = Context::new(solver.params(), &[], None)?;
+            let limit = time_limit(args.timeout);
+            mismatches.extend(verify_day(solver, &input, &ctx, expected, limit));
+        }
+    }
+
+    println!(
+        "Checked {checked} days, {} mismatched parts",
+        mismatches.len()
+    );
+    if without_input > 0 {
+        println!(
+            "Skipped {without_input} days with stored answers but no input in {}",
+            args.inputs_dir.display()
+        );
+    }
+    if !mismatches.is_empty() {
+        println!();
+        println!("{}", MismatchTable(&mismatches));
+    }
+    Ok(mismatches.is_empty())
+}
+
+fn fetch(args: &FetchArgs) -> Result<()> {
+    let client = Client::new(&args.base_url, session_token()?);
+    match fetch_input(&client, &args.inputs_dir, args.year, args.day)? {
+        Fetched::Downloaded(path) => println!("Downloaded {}", path.display()),
+        Fetched::Cached(path) => println!("Already have {}", path.display()),
+    }
+    Ok(())
+}
+
+fn submit_answer(args: &SubmitArgs) -> Result<Verdict> {
+    let answer = match &args.answer {
+        Some(a) => a
+            .parse()
+            .map_err(|e| AocError::parse(format!("invalid answer: {e:?}")))?,
+        None => {
+            let solver = solver::find(args.year, args.day).ok_or(AocError::UnknownDay {
+                year: args.year,
+                day: args.day,
+            })?;
+            let input = read_input(&input_path(&args.inputs_dir, args.year, args.day))?;
+            let ctx = Context::new(solver.params(), &[], Some(args.part))?;
+            let run = run_isolated(solver, &input, &ctx, time_limit(args.timeout));
+            let [x, y] = run.parts;
+            let answer = if args.part == 1 { x } else { y };
+            answer.map_err(|failure| AocError::solver(failure.to_string()))?
+        }
+    };
+    println!(
+        "Submitting {answer} for {} Day {} Part {}",
+        args.year, args.day, args.part
+    );
+
+    let client = Client::new(&args.base_url, session_token()?);
+    let history = history_path(&args.inputs_dir, args.year, args.day);
+    let known_wrong = known_wrong(args)?;
+    let verdict = submit(
+        &client,
+        &history,
+        &known_wrong,
+        args.year,
+        args.day,
+        args.part,
+        answer.clone(),
+    )?;
+    // The guess is already judged and logged, so failing to record it is only a warning.
+    if verdict.is_judgement()
+        && solver::years().contains(&args.year)
+        && let Err(e) = record_verdict(args, &answer, &verdict)
+    {
+        eprintln!("warning: the verdict was not recorded in the solutions file: {e}");
+    }
+    match verdict {
+        Verdict::Correct => println!("That's the right answer!"),
+        Verdict::TooHigh => println!("Wrong: too high"),
+        Verdict::TooLow => println!("Wrong: too low"),
+        Verdict::Wrong => println!("Wrong"),
+        Verdict::RateLimited(Some(wait)) => println!("Rate limited, try again in {wait:?}"),
+        Verdict::RateLimited(None) => println!("Rate limited, try again later"),
+        Verdict::WrongLevel => println!("Part {} is already solved or still locked", args.part),
+    }
+    Ok(verdict)
+}
+
+/// The answers the year's solutions file records as rejected for the part being submitted.
+fn known_wrong(args: &SubmitArgs) -> Result<Vec<Answer>> {
+    if !solver::years().contains(&args.year) {
+        return Ok(Vec::new());
+    }
+    let solutions = Solutions::load(&solutions_path(args.year))?;
+    Ok(solutions
+        .get(args.day)
+        .map(|day| day.parts[args.part as usize - 1].wrong.clone())
+        .unwrap_or_default())
+}
+
+/// Stores a judged answer in the year's solutions file: the right one as solved, a wrong
+/// one among the rejected guesses.
+fn record_verdict(args: &SubmitArgs, answer: &Answer, verdict: &Verdict) -> Result<()> {
+    let mut solutions = Solutions::load(&solutions_path(args.year))?;
+    if *verdict == Verdict::Correct {
+        solutions.set_answer(args.day, args.part, answer, SolutionStatus::Solved);
+    } else {
+        solutions.add_wrong(args.day, args.part, answer);
+    }
+    solutions.save()
+}
+
+fn print_answer(part: u8, answer: &Answer) {
+    match answer {
+        Answer::Text(s) if s.contains('\n') => {
+            println!("  Part {part}:");
+            for line in s.lines() {
+                println!("    {line}");
+            }
+        }
+        _ => println!("  Part {part}: {answer}"),
+    }
+}
+
+/// Runs `solver` on one watched file and prints the answers with their timings, and for
+/// an example any part that differs from the expected answer. Parts run isolated under
+/// `limit`, so that errors, panics and runaway parts are printed and watching carries on.
+fn print_watched(
+    solver: &'static dyn DynSolver,
+    path: &Path,
+    example: bool,
+    limit: Option<Duration>,
+) {
+    println!();
+    println!("{}", path.display());
+    let loaded = if example {
+        Example::load(path).map(|e| (e.input, e.params, Some(e.expected)))
+    } else {
+        read_input(path).map(|input| (input, Vec::new(), None))
+    };
+    let outcome = loaded.and_then(|(input, params, expected)| {
+        let ctx = Context::new(solver.params(), &params, None)?;
+        Ok((run_isolated(solver, &input, &ctx, limit), expected))
+    });
+    let (IsolatedRun { parts, timings }, expected) = match outcome {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            println!("  error: {e}");
+            return;
+        }
+    };
+
+    for (part, outcome) in (1..=2).zip(&parts) {
+        match outcome {
+            Ok(answer) => print_answer(part, answer),
+            Err(failure) => println!("  Part {part}: {failure}"),
+        }
+    }
+    println!(
+        "  parse {:.2?}  part 1 {:.2?}  part 2 {:.2?}",
+        timings.parse, timings.part1, timings.part2
+    );
+    if let Some((a, b)) = expected {
+        for ((part, outcome), expected) in (1..=2).zip(&parts).zip([&a, &b]) {
+            if let Ok(answer) = outcome
+                && PartStatus::check(answer, Some(expected)) == PartStatus::Wrong
+            {
+                println!("  Part {part} is wrong, expected {expected}");
+            }
+        }
+    }
+}
+
+fn watch(args: &WatchArgs) -> Result<bool> {
+    let year = match args.year {
+        Some(year) => year,
+        None => default_year()?,
+    };
+    let day = args.day;
+    let solver = solver::find(year, day).ok_or(AocError::UnknownDay { year, day })?;
+
+    let mut watcher = Watcher::new();
+    loop {
+        if watcher.poll(&watched_files(&args.inputs_dir, year, day)?)? {
+            // Clear the screen and move the cursor to the top left.
+            print!("\x1b[2J\x1b[H");
+            println!("{year} Day {day}, watching for changes (Ctrl-C to stop)");
+            for path in example_paths(&args.inputs_dir, year, day)? {
+                print_watched(solver, &path, true, time_limit(args.timeout));
+            }
+            let input = input_path(&args.inputs_dir, year, day);
+            print_watched(solver, &input, false, time_limit(args.timeout));
+        }
+        thread::sleep(Duration::from_millis(args.interval));
+    }
+}
+
+/// Outcome of solving one day.
+struct Solved {
+    parts: [std::result::Result<Answer, PartFailure>; 2],
+    /// Medians when benchmarking.
+    timings: Timings,
+    bench: Option<DayBench>,
+    /// The example's answers when running on an example.
+    expected: Option<(Answer, Answer)>,
+}
+
+fn solve(args: &Args, solver: &'static dyn DynSolver) -> Result<Solved> {
+    let (year, day) = (solver.year(), solver.day());
+    let (input, params, expected) = match (&args.input, args.example) {
+        (_, Some(n)) => {
+            let example = Example::load(&example_path(&args.inputs_dir, year, day, n as usize))?;
+            // Parameters given on the command line win over the example's.
+            let params = [example.params, args.params.clone()].concat();
+            (example.input, params, Some(example.expected))
+        }
+        (Some(path), None) => (read_input(path)?, args.params.clone(), None),
+        (None, None) => {
+            let input = read_input(&input_path(&args.inputs_dir, year, day))?;
+            (input, args.params.clone(), None)
+        }
+    };
+    let ctx = Context::new(solver.params(), &params, args.part)?;
+    let limit = time_limit(args.timeout);
+    let (parts, timings, bench) = match args.bench {
+        Some(runs) => {
+            let (run, stats) = bench(solver, &input, &ctx, runs, limit);
+            let timings = match &stats {
+                Some(stats) => Timings {
+                    parse: stats.parse.median,
+                    part1: stats.part1.median,
+                    part2: stats.part2.median,
+                    ..Timings::default()
+                },
+                None => run.timings,
+            };
+            (run.parts, timings, stats)
+        }
+        None => {
+            let IsolatedRun { parts, timings } = run_isolated(solver, &input, &ctx, limit);
+            (parts, timings, None)
+        }
+    };
+    Ok(Solved {
+        parts,
+        timings,
+        bench,
+        expected,
+    })
+}
+
+fn main() -> ExitCode {
     let args = Args::parse();
-    let days = match args.day {
-        Some(d) => d..(d + 1),
-        None => 1..26,
+    let result = match &args.command {
+        Some(Command::Fetch(f)) => fetch(f).map(|()| true),
+        Some(Command::Submit(s)) => submit_answer(s).map(|v| v == Verdict::Correct),
+        Some(Command::Verify(v)) => verify(v),
+        Some(Command::New(n)) => new_day(n).map(|()| true),
+        Some(Command::Watch(w)) => watch(w),
+        None => return run(&args),
     };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
 
-    let run = match args.year {
-        2024 => y2024::run,
-        2025 => y2025::run,
-        _ => panic!("Year {} not found", args.year),
+fn run(args: &Args) -> ExitCode {
+    if args.mem && !mem::ENABLED {
+        eprintln!("error: --mem needs a build with `--features mem`");
+        return ExitCode::FAILURE;
+    }
+    let (years, days) = match select_years(&args.year, args.day)
+        .and_then(|years| select_days(&years, args.day).map(|days| (years, days)))
+    {
+        Ok(selected) => selected,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
     };
 
-    let mut solutions: Vec<(u16, u64, u64)> = Vec::new();
-    for day in days {
-        match run(day as u32) {
-            Ok((x, y)) => {
-                solutions.push((day, x, y));
-                println!("{} Day {day}", args.year);
-                println!("  Part 1: {x}");
-                println!("  Part 2: {y}");
+    let text = args.format == Format::Text;
+    let mut failed = false;
+    let mut matrix = StatusMatrix::new(years);
+    let mut records: Vec<PartRecord> = Vec::new();
+    let mut expected: HashMap<u16, HashMap<u32, (Answer, Answer)>> = HashMap::new();
+    let mut solutions: BTreeMap<u16, Vec<(u32, Answer, Answer)>> = BTreeMap::new();
+    let mut benches: Vec<DayBench> = Vec::new();
+    let mut without_input = 0;
+    let solve_day = |&(year, day): &(u16, u32)| {
+        let solver = solver::find(year, day).ok_or(AocError::UnknownDay { year, day })?;
+        Ok(panic::catch_unwind(AssertUnwindSafe(|| {
+            solve(args, solver)
+        })))
+    };
+    let report_day = |&(year, day): &(u16, u32), outcome: Result<thread::Result<_>>| {
+        let outcome = match outcome {
+            Ok(outcome) => outcome,
+            Err(e) => {
+                eprintln!("error: {e}");
+                failed = true;
+                return;
+            }
+        };
+
+        let mut timings: Option<Timings> = None;
+        let (parts, statuses) = match outcome {
+            Ok(Ok(Solved {
+                parts,
+                timings: t,
+                bench: stats,
+                expected: example,
+            })) => {
+                let stored = match &example {
+                    Some(answers) => Some(answers),
+                    None => expected
+                        .entry(year)
+                        .or_insert_with(|| {
+                            read_solutions(year).unwrap_or_else(|e| {
+                                eprintln!("error: {year} solutions: {e}");
+                                HashMap::new()
+                            })
+                        })
+                        .get(&day),
+                };
+                let stored = [stored.map(|s| &s.0), stored.map(|s| &s.1)];
+                let statuses = [0, 1].map(|i| match &parts[i] {
+                    Ok(answer) => PartStatus::check(answer, stored[i]),
+                    Err(failure) => failure.status(),
+                });
+                if text {
+                    println!("{year} Day {day}");
+                    for ((part, outcome), status) in (1..=2).zip(&parts).zip(statuses) {
+                        if args.part.is_none_or(|p| p == part) {
+                            match outcome {
+                                Ok(answer) => print_answer(part, answer),
+                                Err(failure) => println!("  Part {part}: {failure}"),
+                            }
+                            if status == PartStatus::Wrong
+                                && let Some(expected) = stored[part as usize - 1]
+                            {
+                                println!("  Part {part} is wrong, expected {expected}");
+                            }
+                        }
+                    }
+                    match &stats {
+                        Some(stats) => print!("{stats}"),
+                        None if args.bench.is_some() => {
+                            println!("  not benchmarked: a part failed or timed out")
+                        }
+                        None => {}
+                    }
+                    if args.mem {
+                        print!("{}", MemTable(&t));
+                    }
+                }
+                benches.extend(stats);
+
+                let [x, y] = [0, 1].map(|i| parts[i].clone().unwrap_or_default());
+                solutions.entry(year).or_default().push((day, x, y));
+                timings = Some(t);
+                (parts, statuses)
+            }
+            Ok(Err(AocError::MissingInput(_))) if args.day.is_none() => {
+                without_input += 1;
+                let parts = [Ok(Answer::NotImplemented), Ok(Answer::NotImplemented)];
+                (parts, [PartStatus::Missing; 2])
+            }
+            Ok(Err(e)) => {
+                eprintln!("error: {year} Day {day}: {e}");
+                let failure = PartFailure::Error(e.to_string());
+                (
+                    [Err(failure.clone()), Err(failure)],
+                    [PartStatus::Failed; 2],
+                )
+            }
+            Err(payload) => {
+                let failure = PartFailure::Panicked(panic_message(&*payload));
+                eprintln!("error: {year} Day {day}: {failure}");
+                (
+                    [Err(failure.clone()), Err(failure)],
+                    [PartStatus::Panicked; 2],
+                )
             }
-            Err(_) => {
-                println!(
-                    "No implementation found for {} Day {day}. Stopping",
-                    args.year
-                );
-                break;
+        };
+        matrix.insert(year, day, statuses);
+
+        for (part, (outcome, status)) in (1..=2).zip(parts.into_iter().zip(statuses)) {
+            if args.part.is_none_or(|p| p == part) {
+                let (answer, error) = match outcome {
+                    Ok(answer) => (answer, None),
+                    Err(failure) => (Answer::NotImplemented, Some(failure.to_string())),
+                };
+                records.push(PartRecord {
+                    year,
+                    day,
+                    part,
+                    answer,
+                    status,
+                    error,
+                    time: timings.map(|t| if part == 1 { t.part1 } else { t.part2 }),
+                    parse: timings.map(|t| t.parse),
+                    mem: timings
+                        .filter(|_| args.mem)
+                        .map(|t| if part == 1 { t.part1_mem } else { t.part2_mem }),
+                });
             }
         }
+    };
+    for_each_ordered(&days, worker_count(args.jobs), solve_day, report_day);
+
+    if !text {
+        print!("{}", render(args.format, &records));
+    } else {
+        if !benches.is_empty() {
+            println!();
+            println!("{}", Summary(&benches));
+        }
+        if args.day.is_none() {
+            println!();
+            println!("{matrix}");
+        }
     }
+    if without_input > 0 {
+        let what = if args.example.is_some() {
+            "example"
+        } else {
+            "input"
+        };
+        eprintln!(
+            "{without_input} implemented days have no {what} in {}",
+            args.inputs_dir.display()
+        );
+    }
+    failed |= matrix.has_failures();
 
     if args.build_solution_file {
-        let mut buffer = File::create(format!("src/y{}/solutions.txt", args.year)).unwrap();
-        for (d, s1, s2) in solutions {
-            writeln!(buffer, "{d},{s1},{s2}").unwrap();
+        for (year, solutions) in &solutions {
+            if let Err(e) = write_solutions(*year, solutions) {
+                eprintln!("error: {e}");
+                failed = true;
+            }
         }
     }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
 }
@@ -0,0 +1,194 @@
+//! Heap use per stage, counted by a global allocator that is only installed when the
+//! crate is built with the `mem` feature. Counts are kept per thread, so days running in
+//! parallel don't see each other's allocations.
+
+use std::{cell::Cell, fmt};
+
+use serde::Serialize;
+
+use crate::solver::Timings;
+
+/// Whether this build counts allocations.
+pub const ENABLED: bool = cfg!(feature = "mem");
+
+thread_local! {
+    static CURRENT: Cell<i64> = const { Cell::new(0) };
+    static PEAK: Cell<i64> = const { Cell::new(0) };
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+}
+
+#[cfg(feature = "mem")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+
+    use super::{ALLOCATIONS, CURRENT, PEAK};
+
+    struct Counting;
+
+    // The thread locals have no destructors, so they can be used while a thread exits.
+    fn allocated(size: usize) {
+        let _ = CURRENT.try_with(|current| {
+            current.set(current.get() + size as i64);
+            let _ = PEAK.try_with(|peak| peak.set(peak.get().max(current.get())));
+        });
+        let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+    }
+
+    fn freed(size: usize) {
+        let _ = CURRENT.try_with(|current| current.set(current.get() - size as i64));
+    }
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) };
+            freed(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new.is_null() {
+                freed(layout.size());
+                allocated(new_size);
+            }
+            new
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+}
+
+/// Heap use of one stage. Always zero unless [`ENABLED`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct MemStats {
+    /// Most bytes held at once beyond what was held when the stage started.
+    pub peak_bytes: u64,
+    /// Allocations and reallocations made.
+    pub allocations: u64,
+}
+
+/// Runs `f`, counting the heap use of the current thread while it runs.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, MemStats) {
+    let start = CURRENT.get();
+    PEAK.set(start);
+    ALLOCATIONS.set(0);
+    let out = f();
+    let stats = MemStats {
+        peak_bytes: (PEAK.get() - start).max(0) as u64,
+        allocations: ALLOCATIONS.get(),
+    };
+    (out, stats)
+}
+
+/// Bytes in the largest binary unit that keeps the number at least 1.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+/// Time, peak heap and allocations of each stage of a run, one row per stage.
+pub struct MemTable<'a>(pub &'a Timings);
+
+impl fmt::Display for MemTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let t = self.0;
+        writeln!(
+            f,
+            "  {:<8} {:>12} {:>12} {:>12}",
+            "", "time", "peak", "allocs"
+        )?;
+        for (name, time, mem) in [
+            ("parse", t.parse, t.parse_mem),
+            ("part 1", t.part1, t.part1_mem),
+            ("part 2", t.part2, t.part2_mem),
+        ] {
+            writeln!(
+                f,
+                "  {name:<8} {:>12} {:>12} {:>12}",
+                format!("{time:.2?}"),
+                format_bytes(mem.peak_bytes),
+                mem.allocations
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{hint::black_box, time::Duration};
+
+    use crate::{
+        mem::{ENABLED, MemStats, MemTable, format_bytes, measure},
+        solver::Timings,
+    };
+
+    #[test]
+    fn test_measure() {
+        let (v, stats) = measure(|| {
+            let big = black_box(vec![0u8; 10_000]);
+            drop(big);
+            black_box(vec![1u8; 100])
+        });
+        assert_eq!(100, v.len());
+        if ENABLED {
+            assert!(stats.peak_bytes >= 10_000 && stats.peak_bytes < 11_000);
+            assert_eq!(2, stats.allocations);
+        } else {
+            assert_eq!(MemStats::default(), stats);
+        }
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!("0 B", format_bytes(0));
+        assert_eq!("1023 B", format_bytes(1023));
+        assert_eq!("1.5 KiB", format_bytes(1536));
+        assert_eq!("3.0 GiB", format_bytes(3 << 30));
+    }
+
+    #[test]
+    fn test_table() {
+        let t = Timings {
+            part1: Duration::from_millis(2),
+            part1_mem: MemStats {
+                peak_bytes: 2048,
+                allocations: 3,
+            },
+            ..Timings::default()
+        };
+        let table = MemTable(&t).to_string();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(4, lines.len());
+        assert_eq!(
+            "  part 1         2.00ms      2.0 KiB            3",
+            lines[2]
+        );
+    }
+}
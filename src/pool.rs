@@ -0,0 +1,101 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+/// Number of worker threads to use for `requested` jobs: never more than the machine
+/// has cores, so that days don't slow each other down and skew their timings.
+pub fn worker_count(requested: usize) -> usize {
+    let cores = thread::available_parallelism().map_or(1, |n| n.get());
+    requested.clamp(1, cores)
+}
+
+/// Computes `f` for every item on up to `jobs` threads, handing each result to `each`
+/// in the order of `items`, as soon as it and every earlier result are ready.
+pub fn for_each_ordered<T, R>(
+    items: &[T],
+    jobs: usize,
+    f: impl Fn(&T) -> R + Sync,
+    mut each: impl FnMut(&T, R),
+) where
+    T: Sync,
+    R: Send,
+{
+    if jobs <= 1 {
+        for item in items {
+            each(item, f(item));
+        }
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let tx = tx.clone();
+            let (next, f) = (&next, &f);
+            scope.spawn(move || {
+                loop {
+                    let idx = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(idx) else {
+                        break;
+                    };
+                    if tx.send((idx, f(item))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        let mut pending = BTreeMap::new();
+        let mut emitted = 0;
+        for (idx, result) in rx {
+            pending.insert(idx, result);
+            while let Some(result) = pending.remove(&emitted) {
+                each(&items[emitted], result);
+                emitted += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use rstest::rstest;
+
+    use crate::pool::{for_each_ordered, worker_count};
+
+    #[rstest]
+    #[case(1)]
+    #[case(4)]
+    #[case(100)]
+    fn test_order_is_kept(#[case] jobs: usize) {
+        let items: Vec<u64> = (0..20).collect();
+        let mut seen = Vec::new();
+        for_each_ordered(
+            &items,
+            jobs,
+            |x| {
+                // Later items finish first.
+                thread::sleep(Duration::from_millis(20 - x));
+                x * 10
+            },
+            |x, r| seen.push((*x, r)),
+        );
+        assert_eq!(items.iter().map(|x| (*x, x * 10)).collect::<Vec<_>>(), seen);
+    }
+
+    #[test]
+    fn test_worker_count() {
+        assert_eq!(1, worker_count(0));
+        assert_eq!(1, worker_count(1));
+        assert!(worker_count(1000) <= thread::available_parallelism().unwrap().get());
+    }
+}
@@ -0,0 +1,277 @@
+use std::{fmt::Write, time::Duration};
+
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+
+use crate::{
+    answer::Answer,
+    mem::{MemStats, format_bytes},
+    status::PartStatus,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+/// One row of machine-readable output: the outcome of a single part.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartRecord {
+    pub year: u16,
+    pub day: u32,
+    pub part: u8,
+    /// `null` when the part produced no answer.
+    #[serde(serialize_with = "serialize_answer")]
+    pub answer: Answer,
+    pub status: PartStatus,
+    /// Why the part failed, panicked or timed out.
+    pub error: Option<String>,
+    /// Time spent in this part, the median when benchmarking.
+    #[serde(serialize_with = "serialize_nanos", rename = "time_ns")]
+    pub time: Option<Duration>,
+    /// Time spent parsing the day's input, shared by both parts.
+    #[serde(serialize_with = "serialize_nanos", rename = "parse_time_ns")]
+    pub parse: Option<Duration>,
+    /// Heap use of this part with `--mem`, written as `peak_bytes` and `allocations`.
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub mem: Option<MemStats>,
+}
+
+fn serialize_answer<S: Serializer>(answer: &Answer, s: S) -> Result<S::Ok, S::Error> {
+    match answer {
+        Answer::NotImplemented => s.serialize_none(),
+        a => s.serialize_some(&a.to_string()),
+    }
+}
+
+/// Durations are written as whole nanoseconds.
+fn serialize_nanos<S: Serializer>(d: &Option<Duration>, s: S) -> Result<S::Ok, S::Error> {
+    match d {
+        Some(d) => s.serialize_some(&(d.as_nanos() as u64)),
+        None => s.serialize_none(),
+    }
+}
+
+/// Renders `records` in one of the machine-readable formats. Text output is printed
+/// as the runner goes, so it has no rendering here.
+pub fn render(format: Format, records: &[PartRecord]) -> String {
+    match format {
+        Format::Text => String::new(),
+        Format::Json => {
+            serde_json::to_string_pretty(records).expect("records serialize to JSON") + "\n"
+        }
+        Format::Csv => csv(records),
+        Format::Markdown => markdown(records),
+    }
+}
+
+/// Whether any record has heap use, which adds its columns to CSV and Markdown.
+fn has_mem(records: &[PartRecord]) -> bool {
+    records.iter().any(|r| r.mem.is_some())
+}
+
+fn csv(records: &[PartRecord]) -> String {
+    let nanos = |d: Option<Duration>| d.map(|d| d.as_nanos().to_string()).unwrap_or_default();
+    let with_mem = has_mem(records);
+    let mut out = String::from("year,day,part,answer,status,time_ns,parse_time_ns,error");
+    out.push_str(if with_mem {
+        ",peak_bytes,allocations\n"
+    } else {
+        "\n"
+    });
+    for r in records {
+        let answer = match &r.answer {
+            Answer::NotImplemented => String::new(),
+            a => a.to_field(),
+        };
+        let error = match &r.error {
+            Some(e) => Answer::Text(e.clone()).to_field(),
+            None => String::new(),
+        };
+        write!(
+            out,
+            "{},{},{},{answer},{},{},{},{error}",
+            r.year,
+            r.day,
+            r.part,
+            r.status.name(),
+            nanos(r.time),
+            nanos(r.parse)
+        )
+        .unwrap();
+        match r.mem {
+            Some(m) if with_mem => writeln!(out, ",{},{}", m.peak_bytes, m.allocations),
+            None if with_mem => writeln!(out, ",,"),
+            _ => writeln!(out),
+        }
+        .unwrap();
+    }
+    out
+}
+
+fn markdown(records: &[PartRecord]) -> String {
+    let time = |d: Option<Duration>| d.map(|d| format!("{d:.2?}")).unwrap_or_default();
+    let cell = |s: &str| s.replace('|', "\\|").replace('\n', "<br>");
+    let with_mem = has_mem(records);
+    let mut out = if with_mem {
+        String::from(
+            "| Year | Day | Part | Answer | Status | Time | Peak | Allocs | Error |\n\
+             |---:|---:|---:|---|---|---:|---:|---:|---|\n",
+        )
+    } else {
+        String::from(
+            "| Year | Day | Part | Answer | Status | Time | Error |\n\
+             |---:|---:|---:|---|---|---:|---|\n",
+        )
+    };
+    for r in records {
+        let answer = match &r.answer {
+            Answer::NotImplemented => String::new(),
+            a => format!("`{}`", cell(&a.to_string())),
+        };
+        write!(
+            out,
+            "| {} | {} | {} | {answer} | {} | {} |",
+            r.year,
+            r.day,
+            r.part,
+            r.status.name(),
+            time(r.time),
+        )
+        .unwrap();
+        match r.mem {
+            Some(m) if with_mem => {
+                write!(out, " {} | {} |", format_bytes(m.peak_bytes), m.allocations)
+            }
+            None if with_mem => write!(out, "  |  |"),
+            _ => Ok(()),
+        }
+        .unwrap();
+        writeln!(out, " {} |", cell(r.error.as_deref().unwrap_or_default())).unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        answer::Answer,
+        mem::MemStats,
+        report::{Format, PartRecord, render},
+        status::PartStatus,
+    };
+
+    fn records() -> Vec<PartRecord> {
+        vec![
+            PartRecord {
+                year: 2025,
+                day: 3,
+                part: 1,
+                answer: Answer::Integer(357),
+                status: PartStatus::Solved,
+                error: None,
+                time: Some(Duration::from_micros(5)),
+                parse: Some(Duration::from_micros(12)),
+                mem: None,
+            },
+            PartRecord {
+                year: 2025,
+                day: 3,
+                part: 2,
+                answer: Answer::Text("a|b,c".to_string()),
+                status: PartStatus::Wrong,
+                error: None,
+                time: None,
+                parse: None,
+                mem: None,
+            },
+            PartRecord {
+                year: 2025,
+                day: 4,
+                part: 1,
+                answer: Answer::NotImplemented,
+                status: PartStatus::Panicked,
+                error: Some("panicked: index 3, len 2".to_string()),
+                time: Some(Duration::ZERO),
+                parse: Some(Duration::ZERO),
+                mem: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&render(Format::Json, &records())).unwrap();
+        assert_eq!(
+            serde_json::json!([
+                {"year": 2025, "day": 3, "part": 1, "answer": "357", "status": "solved",
+                 "error": null, "time_ns": 5000, "parse_time_ns": 12000},
+                {"year": 2025, "day": 3, "part": 2, "answer": "a|b,c", "status": "wrong",
+                 "error": null, "time_ns": null, "parse_time_ns": null},
+                {"year": 2025, "day": 4, "part": 1, "answer": null, "status": "panicked",
+                 "error": "panicked: index 3, len 2", "time_ns": 0, "parse_time_ns": 0},
+            ]),
+            json
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            "year,day,part,answer,status,time_ns,parse_time_ns,error\n\
+             2025,3,1,357,solved,5000,12000,\n\
+             2025,3,2,\"a|b\\,c\",wrong,,,\n\
+             2025,4,1,,panicked,0,0,\"panicked: index 3\\, len 2\"\n",
+            render(Format::Csv, &records())
+        );
+    }
+
+    #[test]
+    fn test_markdown() {
+        let md = render(Format::Markdown, &records());
+        let lines: Vec<&str> = md.lines().collect();
+        assert_eq!(5, lines.len());
+        assert_eq!("| 2025 | 3 | 1 | `357` | solved | 5.00µs |  |", lines[2]);
+        assert_eq!("| 2025 | 3 | 2 | `a\\|b,c` | wrong |  |  |", lines[3]);
+        assert_eq!(
+            "| 2025 | 4 | 1 |  | panicked | 0.00ns | panicked: index 3, len 2 |",
+            lines[4]
+        );
+    }
+
+    #[test]
+    fn test_mem_columns() {
+        let mut records = records();
+        records[0].mem = Some(MemStats {
+            peak_bytes: 4096,
+            allocations: 7,
+        });
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render(Format::Json, &records)).unwrap();
+        assert_eq!(4096, json[0]["peak_bytes"]);
+        assert_eq!(7, json[0]["allocations"]);
+        assert!(json[1].get("peak_bytes").is_none());
+
+        let csv = render(Format::Csv, &records);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert!(lines[0].ends_with(",error,peak_bytes,allocations"));
+        assert_eq!("2025,3,1,357,solved,5000,12000,,4096,7", lines[1]);
+        assert_eq!("2025,3,2,\"a|b\\,c\",wrong,,,,,", lines[2]);
+
+        let md = render(Format::Markdown, &records);
+        let lines: Vec<&str> = md.lines().collect();
+        assert_eq!(
+            "| 2025 | 3 | 1 | `357` | solved | 5.00µs | 4.0 KiB | 7 |  |",
+            lines[2]
+        );
+        assert_eq!("| 2025 | 3 | 2 | `a\\|b,c` | wrong |  |  |  |  |", lines[3]);
+    }
+}
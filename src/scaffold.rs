@@ -0,0 +1,232 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    error::{AocError, Result},
+    example::example_path,
+};
+
+pub const DEFAULT_SRC_DIR: &str = "src";
+
+pub fn day_path(src_dir: &Path, year: u16, day: u32) -> PathBuf {
+    src_dir
+        .join(format!("y{year}"))
+        .join(format!("day{day}.rs"))
+}
+
+/// Source of a new day that builds as is, with both parts still unimplemented.
+pub fn day_template(year: u16, day: u32) -> String {
+    format!(
+        r#"use crate::{{
+    answer::Answer,
+    error::AocError,
+    solver::{{Context, DynSolver, Solver}},
+}};
+
+pub(crate) static SOLVER: &dyn DynSolver = &Day{day};
+
+struct Day{day};
+
+impl Solver for Day{day} {{
+    const YEAR: u16 = {year};
+    const DAY: u32 = {day};
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {{
+        parse(input)
+    }}
+
+    fn part1(_input: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {{
+        Ok(Answer::NotImplemented)
+    }}
+
+    fn part2(_input: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {{
+        Ok(Answer::NotImplemented)
+    }}
+}}
+
+fn parse(input: &str) -> Result<Vec<&str>, AocError> {{
+    Ok(input.split('\n').map(|line| line.trim()).collect())
+}}
+
+#[cfg(test)]
+mod test {{
+    use crate::y{year}::day{day}::parse;
+
+    #[test]
+    fn test_parse() {{
+        assert_eq!(vec!["a", "b"], parse("a\nb").unwrap());
+    }}
+}}
+"#
+    )
+}
+
+/// An example file with no expected answers yet, for the puzzle's example to be pasted
+/// below the `---` line.
+pub const EXAMPLE_TEMPLATE: &str = "part1:\npart2:\n---\n";
+
+/// Writes the template for `year`/`day` under `src_dir`, creating the year directory if
+/// needed, and declares the new module. The build script registers its solver.
+pub fn create_day(src_dir: &Path, year: u16, day: u32) -> Result<PathBuf> {
+    let path = day_path(src_dir, year, day);
+    create_new(&path, &day_template(year, day))?;
+    declare_days(src_dir, year)?;
+    declare_year(src_dir, year)?;
+    Ok(path)
+}
+
+/// Rewrites `y<year>/mod.rs` to declare every `day<N>.rs` in the year directory, in the
+/// order rustfmt keeps them.
+fn declare_days(src_dir: &Path, year: u16) -> Result<()> {
+    let dir = src_dir.join(format!("y{year}"));
+    let io_err = |e| AocError::Io(dir.clone(), e);
+    let mut days = Vec::new();
+    for entry in fs::read_dir(&dir).map_err(io_err)? {
+        let name = entry.map_err(io_err)?.file_name();
+        if let Some(day) = name.to_string_lossy().strip_suffix(".rs")
+            && day
+                .strip_prefix("day")
+                .is_some_and(|n| n.parse::<u32>().is_ok())
+        {
+            days.push(day.to_string());
+        }
+    }
+    days.sort();
+
+    let path = dir.join("mod.rs");
+    let contents: String = days
+        .iter()
+        .map(|day| format!("pub(crate) mod {day};\n"))
+        .collect();
+    fs::write(&path, contents).map_err(|e| AocError::Io(path, e))
+}
+
+/// Adds `pub mod y<year>;` to `main.rs` next to the other years, unless it is there.
+fn declare_year(src_dir: &Path, year: u16) -> Result<()> {
+    let path = src_dir.join("main.rs");
+    let source = fs::read_to_string(&path).map_err(|e| AocError::Io(path.clone(), e))?;
+    let declaration = format!("pub mod y{year};");
+    let mut lines: Vec<&str> = source.lines().collect();
+    if lines.contains(&declaration.as_str()) {
+        return Ok(());
+    }
+
+    let is_year = |line: &str| {
+        line.strip_prefix("pub mod y")
+            .and_then(|l| l.strip_suffix(';'))
+            .is_some_and(|y| y.parse::<u16>().is_ok())
+    };
+    let at = match lines
+        .iter()
+        .position(|&line| is_year(line) && line > declaration.as_str())
+    {
+        Some(i) => i,
+        None => lines
+            .iter()
+            .rposition(|&line| line.starts_with("pub mod "))
+            .map_or(0, |i| i + 1),
+    };
+    lines.insert(at, &declaration);
+    let mut source = lines.join("\n");
+    source.push('\n');
+    fs::write(&path, source).map_err(|e| AocError::Io(path, e))
+}
+
+/// Writes an empty first example for `year`/`day` under `inputs_dir`, which the example
+/// tests pick up once the answers are filled in.
+pub fn create_example(inputs_dir: &Path, year: u16, day: u32) -> Result<PathBuf> {
+    let path = example_path(inputs_dir, year, day, 1);
+    create_new(&path, EXAMPLE_TEMPLATE)?;
+    Ok(path)
+}
+
+/// Writes `contents` to a new file at `path`, never replacing an existing one.
+fn create_new(path: &Path, contents: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| AocError::Io(dir.to_path_buf(), e))?;
+    }
+
+    let mut file = match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(f) => f,
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+            return Err(AocError::AlreadyExists(path.to_path_buf()));
+        }
+        Err(e) => return Err(AocError::Io(path.to_path_buf(), e)),
+    };
+    file.write_all(contents.as_bytes())
+        .map_err(|e| AocError::Io(path.to_path_buf(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::{
+        error::AocError,
+        example::{Example, example_path},
+        scaffold::{create_day, create_example, day_path, day_template},
+    };
+
+    #[test]
+    fn test_create_day() {
+        let dir = tempfile::tempdir().unwrap();
+        let main = dir.path().join("main.rs");
+        fs::write(
+            &main,
+            "pub mod watch;\npub mod y2024;\npub mod y2027;\n\nfn main() {}\n",
+        )
+        .unwrap();
+        let path = create_day(dir.path(), 2026, 4).unwrap();
+        assert_eq!(day_path(dir.path(), 2026, 4), path);
+
+        let source = fs::read_to_string(&path).unwrap();
+        assert_eq!(day_template(2026, 4), source);
+        assert!(source.contains("struct Day4;"));
+        assert!(source.contains("const YEAR: u16 = 2026;"));
+        assert!(source.contains("use crate::y2026::day4::parse;"));
+        assert!(!source.contains("TEST_INPUT"));
+
+        create_day(dir.path(), 2026, 10).unwrap();
+        assert_eq!(
+            "pub(crate) mod day10;\npub(crate) mod day4;\n",
+            fs::read_to_string(dir.path().join("y2026/mod.rs")).unwrap()
+        );
+        assert_eq!(
+            "pub mod watch;\npub mod y2024;\npub mod y2026;\npub mod y2027;\n\nfn main() {}\n",
+            fs::read_to_string(&main).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_create_example() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = create_example(dir.path(), 2026, 4).unwrap();
+        assert_eq!(example_path(dir.path(), 2026, 4, 1), path);
+
+        let example = Example::load(&path).unwrap();
+        assert!(!example.expected.0.is_implemented());
+        assert!(!example.expected.1.is_implemented());
+        assert!(example.input.is_empty());
+        assert!(matches!(
+            create_example(dir.path(), 2026, 4),
+            Err(AocError::AlreadyExists(p)) if p == path
+        ));
+    }
+
+    #[test]
+    fn test_never_overwrites() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = day_path(dir.path(), 2025, 1);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "keep me").unwrap();
+
+        let err = create_day(dir.path(), 2025, 1).unwrap_err();
+        assert!(matches!(err, AocError::AlreadyExists(p) if p == path));
+        assert_eq!("keep me", fs::read_to_string(&path).unwrap());
+    }
+}
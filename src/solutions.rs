@@ -0,0 +1,459 @@
+//! Stored answers, one TOML file per year:
+//!
+//! ```toml
+//! [day8]
+//! notes = "closest pairs first, joined with union-find"
+//!
+//! [day8.part1]
+//! answer = 40
+//! status = "solved"
+//! wrong = [39, 41]
+//! ```
+//!
+//! An answer is an integer or a string; integers too large for TOML are written as a
+//! string of digits. `status` is `solved` once the answer is known to be right and
+//! `unsolved` until then, and `wrong` lists guesses the site rejected. Updates edit the
+//! file in place, so other days, notes and comments are kept.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use toml_edit::{Array, DocumentMut, Item, Table, Value};
+
+use crate::{
+    answer::Answer,
+    error::{AocError, Result},
+};
+
+/// Where `year`'s solutions live, next to its solvers in this crate's source tree, so
+/// that the path doesn't depend on the working directory.
+pub fn solutions_path(year: u16) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("src/y{year}/solutions.toml"))
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SolutionStatus {
+    #[default]
+    Unsolved,
+    Solved,
+}
+
+impl SolutionStatus {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SolutionStatus::Unsolved => "unsolved",
+            SolutionStatus::Solved => "solved",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartSolution {
+    /// [`Answer::NotImplemented`] if none is recorded.
+    pub answer: Answer,
+    pub status: SolutionStatus,
+    /// Guesses the site rejected.
+    pub wrong: Vec<Answer>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DaySolution {
+    pub notes: Option<String>,
+    pub parts: [PartSolution; 2],
+}
+
+impl DaySolution {
+    /// The answers known to be right, [`Answer::NotImplemented`] for the others.
+    pub fn expected(&self) -> (Answer, Answer) {
+        let [a, b] = self.parts.clone().map(|p| match p.status {
+            SolutionStatus::Solved => p.answer,
+            SolutionStatus::Unsolved => Answer::NotImplemented,
+        });
+        (a, b)
+    }
+}
+
+/// A year's solutions file, loaded for reading and updating.
+#[derive(Debug)]
+pub struct Solutions {
+    path: PathBuf,
+    doc: DocumentMut,
+    days: BTreeMap<u32, DaySolution>,
+}
+
+impl Solutions {
+    /// Loads the file at `path`, or starts an empty one if there is none yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(s) => s,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(AocError::Io(path.to_path_buf(), e)),
+        };
+        let err = |message: String| AocError::parse(format!("{}: {message}", path.display()));
+        let doc: DocumentMut = text.parse().map_err(|e| err(format!("{e}")))?;
+
+        let mut days = BTreeMap::new();
+        for (key, item) in doc.iter() {
+            let (day, day_solution) = parse_day(key, item).map_err(err)?;
+            days.insert(day, day_solution);
+        }
+        Ok(Solutions {
+            path: path.to_path_buf(),
+            doc,
+            days,
+        })
+    }
+
+    pub fn get(&self, day: u32) -> Option<&DaySolution> {
+        self.days.get(&day)
+    }
+
+    /// The answers known to be right for every day in the file.
+    pub fn expected(&self) -> HashMap<u32, (Answer, Answer)> {
+        self.days.iter().map(|(d, s)| (*d, s.expected())).collect()
+    }
+
+    /// Records `answer` for a part; [`Answer::NotImplemented`] removes the recorded one.
+    pub fn set_answer(&mut self, day: u32, part: u8, answer: &Answer, status: SolutionStatus) {
+        let table = self.part_table(day, part);
+        match to_value(answer) {
+            Some(value) => table.insert("answer", Item::Value(value)),
+            None => table.remove("answer"),
+        };
+        table.insert("status", toml_edit::value(status.name()));
+
+        let solution = &mut self.days.entry(day).or_default().parts[part as usize - 1];
+        solution.answer = answer.clone();
+        solution.status = status;
+    }
+
+    /// Records a computed `answer` as solved if the part has no answer yet and the answer
+    /// isn't a rejected guess, returning whether it did. A recorded answer is never
+    /// replaced, so a run that now disagrees with it shows as wrong instead.
+    pub fn add_computed(&mut self, day: u32, part: u8, answer: &Answer) -> bool {
+        let known = self.get(day).map(|d| &d.parts[part as usize - 1]);
+        if !answer.is_implemented()
+            || known.is_some_and(|p| p.answer.is_implemented() || p.wrong.contains(answer))
+        {
+            return false;
+        }
+        self.set_answer(day, part, answer, SolutionStatus::Solved);
+        true
+    }
+
+    /// Adds a rejected guess to a part, unless it is already listed.
+    pub fn add_wrong(&mut self, day: u32, part: u8, answer: &Answer) {
+        let solution = &mut self.days.entry(day).or_default().parts[part as usize - 1];
+        let Some(value) = to_value(answer) else {
+            return;
+        };
+        if solution.wrong.contains(answer) {
+            return;
+        }
+        solution.wrong.push(answer.clone());
+
+        let table = self.part_table(day, part);
+        match table.get_mut("wrong").and_then(|w| w.as_array_mut()) {
+            Some(wrong) => wrong.push(value),
+            None => {
+                let wrong: Array = [value].into_iter().collect();
+                table.insert("wrong", Item::Value(wrong.into()));
+            }
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        fs::write(&self.path, self.doc.to_string()).map_err(|e| AocError::Io(self.path.clone(), e))
+    }
+
+    /// The table of a part, created in day order if missing.
+    fn part_table(&mut self, day: u32, part: u8) -> &mut Table {
+        let root = self.doc.as_table_mut();
+        let key = format!("day{day}");
+        if !root.contains_key(&key) {
+            let mut table = Table::new();
+            table.set_implicit(true);
+            root.insert(&key, Item::Table(table));
+            root.sort_values_by(|a, _, b, _| day_number(a).cmp(&day_number(b)));
+        }
+        root[&key]
+            .as_table_mut()
+            .expect("days are checked to be tables on load")
+            .entry(&format!("part{part}"))
+            .or_insert_with(|| Item::Table(Table::new()))
+            .as_table_mut()
+            .expect("parts are checked to be tables on load")
+    }
+}
+
+fn day_number(key: &str) -> Option<u32> {
+    key.strip_prefix("day")?.parse().ok()
+}
+
+fn parse_day(key: &str, item: &Item) -> std::result::Result<(u32, DaySolution), String> {
+    let day = day_number(key).ok_or_else(|| format!("expected a day like 'day3', got '{key}'"))?;
+    let table = item
+        .as_table()
+        .ok_or_else(|| format!("expected a [{key}] table"))?;
+
+    let mut solution = DaySolution::default();
+    for (name, item) in table.iter() {
+        match name {
+            "notes" => match item.as_str() {
+                Some(notes) => solution.notes = Some(notes.to_string()),
+                None => return Err(format!("{key}.notes: expected a string")),
+            },
+            "part1" | "part2" => {
+                let part = &mut solution.parts[if name == "part1" { 0 } else { 1 }];
+                *part = parse_part(&format!("{key}.{name}"), item)?;
+            }
+            _ => return Err(format!("{key}: unknown key '{name}'")),
+        }
+    }
+    Ok((day, solution))
+}
+
+fn parse_part(key: &str, item: &Item) -> std::result::Result<PartSolution, String> {
+    let table = item
+        .as_table()
+        .ok_or_else(|| format!("expected a [{key}] table"))?;
+    let answer = |value: &Value, field: &str| {
+        from_value(value).ok_or_else(|| format!("{key}.{field}: expected an integer or a string"))
+    };
+
+    let mut part = PartSolution::default();
+    for (name, item) in table.iter() {
+        let value = item
+            .as_value()
+            .ok_or_else(|| format!("{key}.{name}: expected a value"))?;
+        match name {
+            "answer" => part.answer = answer(value, name)?,
+            "status" => {
+                part.status = match value.as_str() {
+                    Some("solved") => SolutionStatus::Solved,
+                    Some("unsolved") => SolutionStatus::Unsolved,
+                    _ => return Err(format!("{key}.status: expected \"solved\" or \"unsolved\"")),
+                }
+            }
+            "wrong" => {
+                let guesses = value
+                    .as_array()
+                    .ok_or_else(|| format!("{key}.wrong: expected a list of answers"))?;
+                part.wrong = guesses
+                    .iter()
+                    .map(|v| answer(v, name))
+                    .collect::<std::result::Result<_, _>>()?;
+            }
+            _ => return Err(format!("{key}: unknown key '{name}'")),
+        }
+    }
+    Ok(part)
+}
+
+fn from_value(value: &Value) -> Option<Answer> {
+    match value {
+        Value::Integer(x) => Some(Answer::Integer(*x.value())),
+        // Integers too big for TOML are written as strings by `to_value`; any other
+        // string is text, even if it is all digits.
+        Value::String(s) => Some(match s.value().parse::<i128>() {
+            Ok(x) if i64::try_from(x).is_err() && x.to_string() == *s.value() => {
+                Answer::BigInteger(x)
+            }
+            _ => Answer::Text(s.value().clone()),
+        }),
+        _ => None,
+    }
+}
+
+fn to_value(answer: &Answer) -> Option<Value> {
+    match answer {
+        Answer::Integer(x) => Some((*x).into()),
+        Answer::BigInteger(x) => Some(x.to_string().into()),
+        Answer::Text(s) => Some(s.as_str().into()),
+        Answer::NotImplemented => None,
+    }
+}
+
+/// The answers known to be right for `year`, by day.
+pub fn read_solutions(year: u16) -> Result<HashMap<u32, (Answer, Answer)>> {
+    Ok(Solutions::load(&solutions_path(year))?.expected())
+}
+
+/// Records the given answers for `year` as solved where no answer is stored yet, see
+/// [`Solutions::add_computed`], leaving every other entry as it is.
+pub fn write_solutions(year: u16, solutions: &[(u32, Answer, Answer)]) -> Result<()> {
+    let mut file = Solutions::load(&solutions_path(year))?;
+    for (day, a, b) in solutions {
+        for (part, answer) in [(1, a), (2, b)] {
+            file.add_computed(*day, part, answer);
+        }
+    }
+    file.save()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::{
+        answer::Answer,
+        error::AocError,
+        solutions::{SolutionStatus, Solutions},
+    };
+
+    const FILE: &str = r#"# Kept across updates
+[day3]
+notes = "greedy"
+
+[day3.part1]
+answer = 357
+status = "solved"
+
+[day3.part2]
+answer = "170141183460469231731687303715884105727"
+status = "unsolved"
+wrong = [1, "abc"]
+
+[day10.part1]
+answer = "a,b"
+status = "solved"
+"#;
+
+    #[test]
+    fn test_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("solutions.toml");
+        fs::write(&path, FILE).unwrap();
+        let solutions = Solutions::load(&path).unwrap();
+
+        let day3 = solutions.get(3).unwrap();
+        assert_eq!(Some("greedy"), day3.notes.as_deref());
+        assert_eq!(Answer::BigInteger(i128::MAX), day3.parts[1].answer);
+        assert_eq!(SolutionStatus::Unsolved, day3.parts[1].status);
+        assert_eq!(
+            vec![Answer::from(1), Answer::from("abc")],
+            day3.parts[1].wrong
+        );
+        assert_eq!((Answer::from(357), Answer::NotImplemented), day3.expected());
+        assert_eq!(
+            (Answer::from("a,b"), Answer::NotImplemented),
+            solutions.expected()[&10]
+        );
+        assert!(solutions.get(4).is_none());
+
+        fs::write(
+            &path,
+            "[day1.part1]\nanswer = \"123\"\nstatus = \"solved\"\n",
+        )
+        .unwrap();
+        let quoted = Solutions::load(&path).unwrap();
+        assert_eq!(Answer::from("123"), quoted.get(1).unwrap().parts[0].answer);
+    }
+
+    #[test]
+    fn test_text_digits_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("solutions.toml");
+        let mut solutions = Solutions::load(&path).unwrap();
+        solutions.set_answer(1, 1, &Answer::from("0123"), SolutionStatus::Solved);
+        solutions.set_answer(1, 2, &Answer::from(-i128::MAX), SolutionStatus::Solved);
+        solutions.save().unwrap();
+
+        let reloaded = Solutions::load(&path).unwrap();
+        assert_eq!(
+            (Answer::from("0123"), Answer::BigInteger(-i128::MAX)),
+            reloaded.get(1).unwrap().expected()
+        );
+    }
+
+    #[test]
+    fn test_missing_file_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let solutions = Solutions::load(&dir.path().join("solutions.toml")).unwrap();
+        assert!(solutions.expected().is_empty());
+    }
+
+    #[test]
+    fn test_update_in_place() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("solutions.toml");
+        fs::write(&path, FILE).unwrap();
+
+        let mut solutions = Solutions::load(&path).unwrap();
+        solutions.set_answer(
+            3,
+            2,
+            &Answer::from(3121910778619i64),
+            SolutionStatus::Solved,
+        );
+        solutions.set_answer(5, 1, &Answer::from(3), SolutionStatus::Solved);
+        solutions.add_wrong(5, 2, &Answer::from(13));
+        solutions.add_wrong(5, 2, &Answer::from(13));
+        solutions.save().unwrap();
+
+        let text = fs::read_to_string(&path).unwrap();
+        assert!(text.starts_with("# Kept across updates\n[day3]\nnotes = \"greedy\""));
+        let day5 = text.find("[day5.part1]").unwrap();
+        assert!(text.find("[day3.part2]").unwrap() < day5);
+        assert!(day5 < text.find("[day10.part1]").unwrap());
+
+        let reloaded = Solutions::load(&path).unwrap();
+        assert_eq!(
+            (Answer::from(357), Answer::from(3121910778619i64)),
+            reloaded.get(3).unwrap().expected()
+        );
+        assert_eq!(
+            vec![Answer::from(1), Answer::from("abc")],
+            reloaded.get(3).unwrap().parts[1].wrong
+        );
+        let day5 = reloaded.get(5).unwrap();
+        assert_eq!((Answer::from(3), Answer::NotImplemented), day5.expected());
+        assert_eq!(vec![Answer::from(13)], day5.parts[1].wrong);
+        assert!(reloaded.get(10).is_some());
+    }
+
+    #[test]
+    fn test_add_computed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("solutions.toml");
+        fs::write(&path, FILE).unwrap();
+        let mut solutions = Solutions::load(&path).unwrap();
+
+        assert!(!solutions.add_computed(3, 1, &Answer::from(358)));
+        assert!(!solutions.add_computed(3, 2, &Answer::from(7)));
+        assert!(!solutions.add_computed(4, 1, &Answer::NotImplemented));
+        assert!(solutions.add_computed(10, 2, &Answer::from(5)));
+        solutions.add_wrong(11, 1, &Answer::from(9));
+        assert!(!solutions.add_computed(11, 1, &Answer::from(9)));
+
+        let day3 = solutions.get(3).unwrap();
+        assert_eq!(Answer::from(357), day3.parts[0].answer);
+        assert_eq!(SolutionStatus::Unsolved, day3.parts[1].status);
+        assert_eq!(
+            (Answer::from("a,b"), Answer::from(5)),
+            solutions.get(10).unwrap().expected()
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("solutions.toml");
+        for text in [
+            "[day3.part1]\nanswer = 1.5\n",
+            "[day3.part1]\nstatus = \"maybe\"\n",
+            "[day3]\npart3 = 1\n",
+            "[three.part1]\nanswer = 1\n",
+            "[day3.part1\n",
+        ] {
+            fs::write(&path, text).unwrap();
+            assert!(
+                matches!(Solutions::load(&path), Err(AocError::Parse { .. })),
+                "{text}"
+            );
+        }
+    }
+}
@@ -0,0 +1,333 @@
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answer::Answer,
+    error::{AocError, Result},
+    mem::{self, MemStats},
+};
+
+pub trait Solver {
+    const YEAR: u16;
+    const DAY: u32;
+
+    /// Tunable values the parts read through [`Context::param`].
+    const PARAMS: &'static [Param] = &[];
+
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+    fn part1(input: &Self::Input<'_>, ctx: &Context) -> Result<Answer>;
+    fn part2(input: &Self::Input<'_>, ctx: &Context) -> Result<Answer>;
+}
+
+/// A value a solver lets callers override with `--param name=value`.
+#[derive(Debug)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+    pub help: &'static str,
+}
+
+/// Shared flag the runner sets when a part has used up its time limit.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Per-run settings handed to each part.
+#[derive(Debug, Clone, Default)]
+pub struct Context {
+    part: Option<u8>,
+    params: HashMap<&'static str, String>,
+    cancel: CancelToken,
+}
+
+impl Context {
+    /// Builds a context for a solver declaring `declared`, rejecting overrides it doesn't
+    /// know about. `part` limits the run to one part.
+    pub fn new(
+        declared: &'static [Param],
+        overrides: &[(String, String)],
+        part: Option<u8>,
+    ) -> Result<Self> {
+        let mut params: HashMap<&'static str, String> = declared
+            .iter()
+            .map(|p| (p.name, p.default.to_string()))
+            .collect();
+        for (name, value) in overrides {
+            let Some(p) = declared.iter().find(|p| p.name == name) else {
+                let known = declared
+                    .iter()
+                    .map(|p| format!("\n  {} (default {}): {}", p.name, p.default, p.help))
+                    .collect::<String>();
+                return Err(AocError::Param(if known.is_empty() {
+                    format!("unknown parameter '{name}', this day takes none")
+                } else {
+                    format!("unknown parameter '{name}', expected one of:{known}")
+                }));
+            };
+            params.insert(p.name, value.clone());
+        }
+        Ok(Context {
+            part,
+            params,
+            cancel: CancelToken::default(),
+        })
+    }
+
+    pub fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+
+    /// A copy that only runs `part`, with a token of its own.
+    pub fn for_part(&self, part: u8) -> Self {
+        Context {
+            part: Some(part),
+            params: self.params.clone(),
+            cancel: CancelToken::default(),
+        }
+    }
+
+    pub fn cancel_token(&self) -> &CancelToken {
+        &self.cancel
+    }
+
+    /// Fails with [`AocError::Cancelled`] once the runner has given up on this part.
+    /// Searches that may run for long should call this as they go.
+    pub fn check_cancelled(&self) -> Result<()> {
+        if self.cancel.is_cancelled() {
+            Err(AocError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// The value of the declared parameter `name`, parsed as `T`.
+    pub fn param<T>(&self, name: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Debug,
+    {
+        let value = self
+            .params
+            .get(name)
+            .ok_or_else(|| AocError::Param(format!("parameter '{name}' is not declared")))?;
+        value
+            .parse()
+            .map_err(|e| AocError::Param(format!("invalid value '{value}' for '{name}': {e:?}")))
+    }
+}
+
+/// Wall-clock time spent in each stage of a single run, and the heap it used when built
+/// with the `mem` feature.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+    pub parse_mem: MemStats,
+    pub part1_mem: MemStats,
+    pub part2_mem: MemStats,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+/// Object-safe view of a [`Solver`], so that days with different input types can share
+/// one registry.
+pub trait DynSolver: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u32;
+    fn params(&self) -> &'static [Param];
+
+    /// Runs the parts `ctx` selects; a part that is skipped answers
+    /// [`Answer::NotImplemented`].
+    fn run_timed(&self, input: &str, ctx: &Context) -> Result<(Answer, Answer, Timings)>;
+
+    fn run(&self, input: &str) -> Result<(Answer, Answer)> {
+        let ctx = Context::new(self.params(), &[], None)?;
+        let (a, b, _) = self.run_timed(input, &ctx)?;
+        Ok((a, b))
+    }
+}
+
+impl<S: Solver + Sync> DynSolver for S {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn params(&self) -> &'static [Param] {
+        S::PARAMS
+    }
+
+    fn run_timed(&self, input: &str, ctx: &Context) -> Result<(Answer, Answer, Timings)> {
+        let start = Instant::now();
+        let (input, parse_mem) = mem::measure(|| S::parse(input));
+        let input = input?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        let (a, part1_mem) = mem::measure(|| {
+            if ctx.runs_part(1) {
+                S::part1(&input, ctx)
+            } else {
+                Ok(Answer::NotImplemented)
+            }
+        });
+        let a = a?;
+        let part1 = start.elapsed();
+
+        let start = Instant::now();
+        let (b, part2_mem) = mem::measure(|| {
+            if ctx.runs_part(2) {
+                S::part2(&input, ctx)
+            } else {
+                Ok(Answer::NotImplemented)
+            }
+        });
+        let b = b?;
+        let part2 = start.elapsed();
+
+        Ok((
+            a,
+            b,
+            Timings {
+                parse,
+                part1,
+                part2,
+                parse_mem,
+                part1_mem,
+                part2_mem,
+            },
+        ))
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+/// Every implemented day, ordered by year then day.
+pub fn registry() -> impl Iterator<Item = &'static dyn DynSolver> {
+    SOLVERS.iter().copied()
+}
+
+pub fn find(year: u16, day: u32) -> Option<&'static dyn DynSolver> {
+    registry().find(|s| s.year() == year && s.day() == day)
+}
+
+/// Every year with at least one registered day, in order.
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = registry().map(|s| s.year()).collect();
+    years.dedup();
+    years
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::HashMap, path::Path};
+
+    use crate::{
+        answer::Answer,
+        error::AocError,
+        input::{DEFAULT_INPUTS_DIR, input_path, read_input},
+        isolate::{DEFAULT_TIMEOUT_SECS, time_limit},
+        solutions::read_solutions,
+        solver::{Context, Param, find, registry, years},
+        verify::{MismatchTable, verify_day},
+    };
+
+    const PARAMS: &[Param] = &[Param {
+        name: "n",
+        default: "3",
+        help: "",
+    }];
+
+    #[test]
+    fn test_registry_is_sorted() {
+        let keys: Vec<(u16, u32)> = registry().map(|s| (s.year(), s.day())).collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted, keys);
+    }
+
+    #[test]
+    fn test_years() {
+        let years = years();
+        assert!(years.is_sorted() && !years.is_empty());
+        assert!(years.contains(&2024) && years.contains(&2025));
+        assert!(registry().all(|s| years.contains(&s.year())));
+    }
+
+    #[test]
+    fn test_context_params() {
+        let ctx = Context::new(PARAMS, &[], None).unwrap();
+        assert_eq!(3, ctx.param::<usize>("n").unwrap());
+
+        let ctx = Context::new(PARAMS, &[("n".to_string(), "7".to_string())], None).unwrap();
+        assert_eq!(7, ctx.param::<usize>("n").unwrap());
+        assert!(ctx.param::<usize>("m").is_err());
+
+        let ctx = Context::new(PARAMS, &[("n".to_string(), "x".to_string())], None).unwrap();
+        assert!(matches!(ctx.param::<usize>("n"), Err(AocError::Param(_))));
+
+        let res = Context::new(PARAMS, &[("m".to_string(), "1".to_string())], None);
+        assert!(matches!(res, Err(AocError::Param(_))));
+    }
+
+    #[test]
+    fn test_run_single_part_with_params() {
+        let solver = find(2025, 3).unwrap();
+        let overrides = [("unsafe_batteries".to_string(), "3".to_string())];
+        let ctx = Context::new(solver.params(), &overrides, Some(2)).unwrap();
+        let (a, b, _) = solver.run_timed("987654321111111", &ctx).unwrap();
+        assert_eq!(Answer::NotImplemented, a);
+        assert_eq!(Answer::Integer(987), b);
+    }
+
+    #[test]
+    fn test_solutions() {
+        let mut solutions_by_year = HashMap::new();
+        for solver in registry() {
+            let (year, day) = (solver.year(), solver.day());
+            let solutions = solutions_by_year
+                .entry(year)
+                .or_insert_with(|| read_solutions(year).unwrap());
+            let Some(expected) = solutions.get(&day) else {
+                continue;
+            };
+            let input = match read_input(&input_path(Path::new(DEFAULT_INPUTS_DIR), year, day)) {
+                Ok(input) => input,
+                Err(AocError::MissingInput(_)) => continue,
+                Err(e) => panic!("{e}"),
+            };
+            let ctx = Context::new(solver.params(), &[], None).unwrap();
+            let limit = time_limit(DEFAULT_TIMEOUT_SECS);
+            let mismatches = verify_day(solver, &input, &ctx, expected, limit);
+            assert!(mismatches.is_empty(), "{}", MismatchTable(&mismatches));
+        }
+    }
+}
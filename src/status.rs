@@ -0,0 +1,165 @@
+use std::{collections::BTreeMap, fmt, ops::RangeInclusive};
+
+use serde::{Serialize, Serializer};
+
+use crate::answer::Answer;
+
+/// Outcome of one part of one day in a full run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartStatus {
+    /// An answer was produced and agrees with the stored solution, if there is one.
+    Solved,
+    /// No solver, no input, or the part is not implemented.
+    Missing,
+    /// The answer differs from the stored solution.
+    Wrong,
+    Panicked,
+    /// The solver returned an error.
+    Failed,
+    /// The part ran past its time limit.
+    TimedOut,
+}
+
+impl PartStatus {
+    /// Compares a computed answer with the stored one.
+    pub fn check(answer: &Answer, expected: Option<&Answer>) -> Self {
+        match expected {
+            _ if !answer.is_implemented() => PartStatus::Missing,
+            Some(e) if e.is_implemented() && e != answer => PartStatus::Wrong,
+            _ => PartStatus::Solved,
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            PartStatus::Wrong | PartStatus::Panicked | PartStatus::Failed | PartStatus::TimedOut
+        )
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PartStatus::Solved => "solved",
+            PartStatus::Missing => "missing",
+            PartStatus::Wrong => "wrong",
+            PartStatus::Panicked => "panicked",
+            PartStatus::Failed => "failed",
+            PartStatus::TimedOut => "timed-out",
+        }
+    }
+
+    fn symbol(&self) -> char {
+        match self {
+            PartStatus::Solved => '*',
+            PartStatus::Missing => '.',
+            PartStatus::Wrong => 'x',
+            PartStatus::Panicked => '!',
+            PartStatus::Failed => 'E',
+            PartStatus::TimedOut => 'T',
+        }
+    }
+}
+
+impl Serialize for PartStatus {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self.name())
+    }
+}
+
+/// Calendar of part statuses, one row per year and one column per day.
+pub struct StatusMatrix {
+    years: RangeInclusive<u16>,
+    days: BTreeMap<(u16, u32), [PartStatus; 2]>,
+}
+
+impl StatusMatrix {
+    pub fn new(years: RangeInclusive<u16>) -> Self {
+        StatusMatrix {
+            years,
+            days: BTreeMap::new(),
+        }
+    }
+
+    pub fn insert(&mut self, year: u16, day: u32, parts: [PartStatus; 2]) {
+        self.days.insert((year, day), parts);
+    }
+
+    pub fn get(&self, year: u16, day: u32) -> [PartStatus; 2] {
+        self.days
+            .get(&(year, day))
+            .copied()
+            .unwrap_or([PartStatus::Missing; 2])
+    }
+
+    pub fn has_failures(&self) -> bool {
+        self.days.values().flatten().any(|s| s.is_failure())
+    }
+}
+
+impl fmt::Display for StatusMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "    ")?;
+        for day in 1..=25 {
+            write!(f, " {day:>2}")?;
+        }
+        writeln!(f)?;
+        for year in self.years.clone() {
+            write!(f, "{year}")?;
+            for day in 1..=25 {
+                let [a, b] = self.get(year, day);
+                write!(f, " {}{}", a.symbol(), b.symbol())?;
+            }
+            writeln!(f)?;
+        }
+        write!(
+            f,
+            "* solved  . missing  x wrong  ! panicked  E error  T timed out   (two marks per day: part 1, part 2)"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answer::Answer,
+        status::{PartStatus, StatusMatrix},
+    };
+
+    #[test]
+    fn test_check() {
+        let a = Answer::Integer(3);
+        assert_eq!(PartStatus::Solved, PartStatus::check(&a, None));
+        assert_eq!(PartStatus::Solved, PartStatus::check(&a, Some(&a)));
+        assert_eq!(
+            PartStatus::Solved,
+            PartStatus::check(&a, Some(&Answer::NotImplemented))
+        );
+        assert_eq!(
+            PartStatus::Wrong,
+            PartStatus::check(&a, Some(&Answer::Integer(4)))
+        );
+        assert_eq!(
+            PartStatus::Missing,
+            PartStatus::check(&Answer::NotImplemented, Some(&a))
+        );
+    }
+
+    #[test]
+    fn test_matrix() {
+        let mut m = StatusMatrix::new(2024..=2025);
+        m.insert(2024, 1, [PartStatus::Solved, PartStatus::Solved]);
+        m.insert(2025, 3, [PartStatus::Wrong, PartStatus::Panicked]);
+        assert!(m.has_failures());
+
+        let lines: Vec<String> = m.to_string().lines().map(String::from).collect();
+        assert_eq!(4, lines.len());
+        assert!(lines[0].starts_with("      1  2  3"));
+        assert!(lines[1].starts_with("2024 ** .. .."));
+        assert!(lines[2].starts_with("2025 .. .. x!"));
+        assert_eq!(4 + 25 * 3, lines[1].len());
+
+        let mut timed_out = StatusMatrix::new(2025..=2025);
+        timed_out.insert(2025, 10, [PartStatus::Solved, PartStatus::TimedOut]);
+        assert!(timed_out.has_failures());
+    }
+}
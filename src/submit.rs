@@ -0,0 +1,375 @@
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use regex::Regex;
+
+use crate::{
+    answer::Answer,
+    error::{AocError, Result},
+    fetch::Client,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The answer was not checked; `None` if the page didn't say how long to wait.
+    RateLimited(Option<Duration>),
+    /// The part is already solved or not unlocked yet, so the answer was not checked.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page returned after posting an answer.
+    pub fn classify(html: &str) -> Option<Verdict> {
+        if html.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if html.contains("You gave an answer too recently") {
+            let re = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+            let wait = re.captures(html).map(|c| {
+                let minutes: u64 = c.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+                let seconds: u64 = c[2].parse().unwrap();
+                Duration::from_secs(minutes * 60 + seconds)
+            });
+            Some(Verdict::RateLimited(wait))
+        } else if html.contains("That's not the right answer") {
+            if html.contains("your answer is too high") {
+                Some(Verdict::TooHigh)
+            } else if html.contains("your answer is too low") {
+                Some(Verdict::TooLow)
+            } else {
+                Some(Verdict::Wrong)
+            }
+        } else if html.contains("You don't seem to be solving the right level") {
+            Some(Verdict::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the server actually judged the answer.
+    pub fn is_judgement(&self) -> bool {
+        !matches!(self, Verdict::RateLimited(_) | Verdict::WrongLevel)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited(_) => write!(f, "rate-limited"),
+            Verdict::WrongLevel => write!(f, "wrong-level"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            "rate-limited" => Ok(Verdict::RateLimited(None)),
+            "wrong-level" => Ok(Verdict::WrongLevel),
+            _ => Err(format!("unknown verdict '{s}'")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub part: u8,
+    pub answer: Answer,
+    pub verdict: Verdict,
+}
+
+/// Where past guesses for a day are kept, next to its input.
+pub fn history_path(inputs_dir: &Path, year: u16, day: u32) -> PathBuf {
+    inputs_dir
+        .join(year.to_string())
+        .join(format!("day{day}.guesses"))
+}
+
+/// Every answer submitted for a day, one `part,answer,verdict` line each.
+#[derive(Debug, Default)]
+pub struct History {
+    pub guesses: Vec<Guess>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(s) => s,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(History::default()),
+            Err(e) => return Err(AocError::Io(path.to_path_buf(), e)),
+        };
+
+        let mut guesses = Vec::new();
+        for (idx, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let cols: Vec<&str> = Answer::split_fields(line).collect();
+            let [part, answer, verdict] = cols[..] else {
+                return Err(AocError::parse_line(
+                    idx + 1,
+                    "expected part,answer,verdict",
+                ));
+            };
+            let err = |msg: String| AocError::parse_line(idx + 1, msg);
+            guesses.push(Guess {
+                part: part
+                    .parse()
+                    .map_err(|_| err(format!("invalid part '{part}'")))?,
+                answer: answer
+                    .parse()
+                    .map_err(|e| err(format!("invalid answer: {e:?}")))?,
+                verdict: verdict.parse().map_err(err)?,
+            });
+        }
+        Ok(History { guesses })
+    }
+
+    pub fn append(path: &Path, guess: &Guess) -> Result<()> {
+        let io_err = |e| AocError::Io(path.to_path_buf(), e);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_err)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(io_err)?;
+        writeln!(
+            file,
+            "{},{},{}",
+            guess.part,
+            guess.answer.to_field(),
+            guess.verdict
+        )
+        .map_err(io_err)
+    }
+
+    /// Explains why `answer` should not be sent for `part`, if the history already rules
+    /// it out.
+    pub fn refusal(&self, part: u8, answer: &Answer) -> Option<String> {
+        let judged = self
+            .guesses
+            .iter()
+            .filter(|g| g.part == part && g.verdict.is_judgement());
+
+        let mut lowest_high: Option<i128> = None;
+        let mut highest_low: Option<i128> = None;
+        for guess in judged {
+            if guess.verdict == Verdict::Correct {
+                return Some(format!(
+                    "part {part} was already solved with {}",
+                    guess.answer
+                ));
+            }
+            if guess.answer == *answer {
+                return Some(format!("{answer} was already submitted: {}", guess.verdict));
+            }
+            match (guess.verdict, as_number(&guess.answer)) {
+                (Verdict::TooHigh, Some(x)) => {
+                    lowest_high = Some(lowest_high.map_or(x, |h| h.min(x)))
+                }
+                (Verdict::TooLow, Some(x)) => {
+                    highest_low = Some(highest_low.map_or(x, |l| l.max(x)))
+                }
+                _ => {}
+            }
+        }
+
+        let x = as_number(answer)?;
+        if let Some(high) = lowest_high
+            && x >= high
+        {
+            return Some(format!("{answer} is not below {high}, which was too high"));
+        }
+        if let Some(low) = highest_low
+            && x <= low
+        {
+            return Some(format!("{answer} is not above {low}, which was too low"));
+        }
+        None
+    }
+}
+
+fn as_number(answer: &Answer) -> Option<i128> {
+    match answer {
+        Answer::Integer(x) => Some(*x as i128),
+        Answer::BigInteger(x) => Some(*x),
+        _ => None,
+    }
+}
+
+/// Submits `answer` unless the history or the `known_wrong` answers from the solutions
+/// file rule it out, and records the verdict.
+pub fn submit(
+    client: &Client,
+    history_file: &Path,
+    known_wrong: &[Answer],
+    year: u16,
+    day: u32,
+    part: u8,
+    answer: Answer,
+) -> Result<Verdict> {
+    if !answer.is_implemented() {
+        return Err(AocError::SubmitRefused(format!(
+            "part {part} is not implemented"
+        )));
+    }
+    if known_wrong.contains(&answer) {
+        return Err(AocError::SubmitRefused(format!(
+            "{answer} is recorded as a wrong answer"
+        )));
+    }
+    if let Some(reason) = History::load(history_file)?.refusal(part, &answer) {
+        return Err(AocError::SubmitRefused(reason));
+    }
+
+    let html = client.answer(year, day, part, &answer.to_string())?;
+    let verdict = Verdict::classify(&html)
+        .ok_or_else(|| AocError::Http("unrecognised reply to the submitted answer".to_string()))?;
+    History::append(
+        history_file,
+        &Guess {
+            part,
+            answer,
+            verdict,
+        },
+    )?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rstest::rstest;
+
+    use crate::{
+        answer::Answer,
+        error::AocError,
+        fetch::{Client, stub},
+        submit::{Guess, History, Verdict, submit},
+    };
+
+    const CORRECT: &str =
+        "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+    const TOO_HIGH: &str =
+        "<article><p>That's not the right answer; your answer is too high.</p></article>";
+
+    #[rstest]
+    #[case(CORRECT, Some(Verdict::Correct))]
+    #[case(TOO_HIGH, Some(Verdict::TooHigh))]
+    #[case(
+        "<p>That's not the right answer; your answer is too low. Please wait one minute.</p>",
+        Some(Verdict::TooLow)
+    )]
+    #[case(
+        "<p>That's not the right answer. If you're stuck...</p>",
+        Some(Verdict::Wrong)
+    )]
+    #[case(
+        "<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.</p>",
+        Some(Verdict::RateLimited(Some(Duration::from_secs(65))))
+    )]
+    #[case(
+        "<p>You gave an answer too recently. You have 38s left to wait.</p>",
+        Some(Verdict::RateLimited(Some(Duration::from_secs(38))))
+    )]
+    #[case(
+        "<p>You don't seem to be solving the right level.  Did you already complete it?</p>",
+        Some(Verdict::WrongLevel)
+    )]
+    #[case("<html>maintenance</html>", None)]
+    fn test_classify(#[case] html: &str, #[case] expected: Option<Verdict>) {
+        assert_eq!(expected, Verdict::classify(html));
+    }
+
+    fn guess(part: u8, answer: i64, verdict: Verdict) -> Guess {
+        Guess {
+            part,
+            answer: answer.into(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn test_refusal() {
+        let history = History {
+            guesses: vec![
+                guess(1, 500, Verdict::TooHigh),
+                guess(1, 100, Verdict::TooLow),
+                guess(1, 300, Verdict::Wrong),
+                guess(1, 200, Verdict::RateLimited(None)),
+                guess(2, 7, Verdict::Correct),
+            ],
+        };
+        assert!(history.refusal(1, &Answer::Integer(300)).is_some());
+        assert!(history.refusal(1, &Answer::Integer(500)).is_some());
+        assert!(history.refusal(1, &Answer::Integer(600)).is_some());
+        assert!(history.refusal(1, &Answer::Integer(100)).is_some());
+        assert!(history.refusal(1, &Answer::Integer(200)).is_none());
+        assert!(history.refusal(1, &Answer::Integer(250)).is_none());
+        assert!(history.refusal(2, &Answer::Integer(8)).is_some());
+    }
+
+    #[test]
+    fn test_submit_records_history() {
+        let (url, requests) = stub::serve(vec![(200, TOO_HIGH), (200, CORRECT)]);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day1.guesses");
+        let client = Client::new(&url, "abc".to_string());
+
+        let verdict = submit(&client, &path, &[], 2025, 1, 1, Answer::Integer(42)).unwrap();
+        assert_eq!(Verdict::TooHigh, verdict);
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2025/day/1/answer "));
+        assert!(request.ends_with("level=1&answer=42"));
+
+        let err = submit(&client, &path, &[], 2025, 1, 1, Answer::Integer(50)).unwrap_err();
+        assert!(matches!(err, AocError::SubmitRefused(_)));
+
+        let verdict = submit(&client, &path, &[], 2025, 1, 1, Answer::Integer(41)).unwrap();
+        assert_eq!(Verdict::Correct, verdict);
+
+        let history = History::load(&path).unwrap();
+        assert_eq!(
+            vec![
+                guess(1, 42, Verdict::TooHigh),
+                guess(1, 41, Verdict::Correct)
+            ],
+            history.guesses
+        );
+    }
+
+    #[test]
+    fn test_submit_refuses_known_wrong() {
+        let (url, _requests) = stub::serve(vec![]);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day1.guesses");
+        let client = Client::new(&url, "abc".to_string());
+
+        let wrong = [Answer::Integer(7)];
+        let err = submit(&client, &path, &wrong, 2025, 1, 2, Answer::Integer(7)).unwrap_err();
+        assert!(matches!(err, AocError::SubmitRefused(_)));
+        assert!(History::load(&path).unwrap().guesses.is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+use std::{fmt, time::Duration};
+
+use crate::{
+    answer::Answer,
+    isolate::run_isolated,
+    solver::{Context, DynSolver},
+};
+
+/// A part whose answer no longer matches the stored solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub year: u16,
+    pub day: u32,
+    pub part: u8,
+    pub expected: Answer,
+    /// The answer produced, or why none was.
+    pub actual: Result<Answer, String>,
+}
+
+/// Runs `solver` on `input` and compares each part that has an expected answer. Each part
+/// runs isolated, so one that fails or runs past `limit` only fails itself.
+pub fn verify_day(
+    solver: &'static dyn DynSolver,
+    input: &str,
+    ctx: &Context,
+    expected: &(Answer, Answer),
+    limit: Option<Duration>,
+) -> Vec<Mismatch> {
+    let actual = run_isolated(solver, input, ctx, limit)
+        .parts
+        .map(|part| part.map_err(|failure| failure.to_string()));
+
+    (1..=2)
+        .zip([&expected.0, &expected.1].into_iter().zip(actual))
+        .filter(|(_, (exp, _))| exp.is_implemented())
+        .filter(|(_, (exp, act))| act.as_ref() != Ok(*exp))
+        .map(|(part, (exp, act))| Mismatch {
+            year: solver.year(),
+            day: solver.day(),
+            part,
+            expected: exp.clone(),
+            actual: act,
+        })
+        .collect()
+}
+
+/// Table of mismatches, one row per part.
+pub struct MismatchTable<'a>(pub &'a [Mismatch]);
+
+impl fmt::Display for MismatchTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let actual = |m: &Mismatch| match &m.actual {
+            Ok(a) => a.to_string(),
+            Err(e) => format!("error: {e}"),
+        };
+        let width = self
+            .0
+            .iter()
+            .map(|m| m.expected.to_string().len())
+            .chain([8])
+            .max()
+            .unwrap_or_default();
+
+        write!(f, "Year  Day  Part  {:<width$}  Actual", "Expected")?;
+        for m in self.0 {
+            write!(
+                f,
+                "\n{}  {:>3}  {:>4}  {:<width$}  {}",
+                m.year,
+                m.day,
+                m.part,
+                m.expected.to_string(),
+                actual(m)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        answer::Answer,
+        solver::{Context, find},
+        verify::{Mismatch, MismatchTable, verify_day},
+    };
+
+    const INPUT: &str = "987654321111111\n811111111111119";
+    const LIMIT: Option<Duration> = Some(Duration::from_secs(10));
+
+    #[test]
+    fn test_verify_day() {
+        let solver = find(2025, 3).unwrap();
+        let ctx = Context::new(solver.params(), &[], None).unwrap();
+        let ok = (Answer::Integer(187), Answer::NotImplemented);
+        assert!(verify_day(solver, INPUT, &ctx, &ok, LIMIT).is_empty());
+
+        let wrong = (Answer::Integer(187), Answer::Integer(1));
+        assert_eq!(
+            vec![Mismatch {
+                year: 2025,
+                day: 3,
+                part: 2,
+                expected: Answer::Integer(1),
+                actual: Ok(Answer::Integer(1798765432230)),
+            }],
+            verify_day(solver, INPUT, &ctx, &wrong, LIMIT)
+        );
+
+        let failed = verify_day(solver, "12x", &ctx, &wrong, LIMIT);
+        assert_eq!(2, failed.len());
+        assert!(failed.iter().all(|m| m.actual.is_err()));
+    }
+
+    #[test]
+    fn test_table() {
+        let table = MismatchTable(&[Mismatch {
+            year: 2025,
+            day: 3,
+            part: 2,
+            expected: Answer::Integer(1),
+            actual: Err("boom".to_string()),
+        }])
+        .to_string();
+        assert_eq!(
+            "Year  Day  Part  Expected  Actual\n2025    3     2  1         error: boom",
+            table
+        );
+    }
+}
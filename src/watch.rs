@@ -0,0 +1,99 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use crate::{
+    error::{AocError, Result},
+    example::example_paths,
+    input::input_path,
+};
+
+/// How often `aoc watch` looks for changes.
+pub const DEFAULT_INTERVAL_MS: u64 = 500;
+
+/// The files `aoc watch` re-runs a day for: its input and every one of its examples.
+pub fn watched_files(inputs_dir: &Path, year: u16, day: u32) -> Result<Vec<PathBuf>> {
+    let mut files = example_paths(inputs_dir, year, day)?;
+    files.push(input_path(inputs_dir, year, day));
+    Ok(files)
+}
+
+/// Remembers the modification time of each watched file, so that polling can tell when
+/// one is created, changed or removed.
+#[derive(Debug, Default)]
+pub struct Watcher {
+    seen: BTreeMap<PathBuf, Option<SystemTime>>,
+}
+
+impl Watcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Checks `files` against the previous poll, returning whether anything changed. The
+    /// first poll always counts as a change.
+    pub fn poll(&mut self, files: &[PathBuf]) -> Result<bool> {
+        let mut now = BTreeMap::new();
+        for path in files {
+            let modified = match fs::metadata(path).and_then(|m| m.modified()) {
+                Ok(t) => Some(t),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+                Err(e) => return Err(AocError::Io(path.clone(), e)),
+            };
+            now.insert(path.clone(), modified);
+        }
+
+        let changed = self.seen.is_empty() || now != self.seen;
+        self.seen = now;
+        Ok(changed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs::{self, File},
+        time::{Duration, SystemTime},
+    };
+
+    use crate::{
+        example::example_path,
+        input::input_path,
+        watch::{Watcher, watched_files},
+    };
+
+    #[test]
+    fn test_poll() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("2025")).unwrap();
+        let input = input_path(dir.path(), 2025, 3);
+        fs::write(&input, "123").unwrap();
+
+        let files = || watched_files(dir.path(), 2025, 3).unwrap();
+        let mut watcher = Watcher::new();
+        assert!(watcher.poll(&files()).unwrap());
+        assert!(!watcher.poll(&files()).unwrap());
+
+        // Set the time explicitly: two writes can land within the same mtime tick.
+        let later = SystemTime::now() + Duration::from_secs(10);
+        File::options()
+            .write(true)
+            .open(&input)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        assert!(watcher.poll(&files()).unwrap());
+        assert!(!watcher.poll(&files()).unwrap());
+
+        fs::write(example_path(dir.path(), 2025, 3, 1), "---\n1").unwrap();
+        assert!(watcher.poll(&files()).unwrap());
+        assert!(!watcher.poll(&files()).unwrap());
+
+        fs::remove_file(&input).unwrap();
+        assert!(watcher.poll(&files()).unwrap());
+        assert!(!watcher.poll(&files()).unwrap());
+    }
+}
@@ -1,17 +1,21 @@
 use std::collections::HashMap;
 
-const INPUT: &str = include_str!("input/day1.txt");
+use crate::{
+    answer::Answer,
+    error::AocError,
+    solver::{Context, DynSolver, Solver},
+};
 
-fn distance(a: &mut Vec<i32>, b: &mut Vec<i32>) -> u32 {
+fn distance(a: &mut [i32], b: &mut [i32]) -> u32 {
     a.sort();
     b.sort();
     a.iter()
         .enumerate()
-        .map(|(idx, x)| (x - b[idx]).abs() as u32)
+        .map(|(idx, x)| (x - b[idx]).unsigned_abs())
         .sum()
 }
 
-fn similarity(a: &mut Vec<i32>, b: &mut Vec<i32>) -> i32 {
+fn similarity(a: &mut [i32], b: &mut [i32]) -> i32 {
     let mut counts: HashMap<i32, usize> = HashMap::new();
     a.iter()
         .map(|x| {
@@ -22,55 +26,49 @@ fn similarity(a: &mut Vec<i32>, b: &mut Vec<i32>) -> i32 {
         .sum()
 }
 
-fn parse(input: &str, col1: &mut Vec<i32>, col2: &mut Vec<i32>) {
-    for l in input.split('\n') {
-        for (idx, x) in l.split_ascii_whitespace().enumerate() {
-            match idx {
-                0 => col1.push(x.parse().expect("Could not parse {x}")),
-                1 => col2.push(x.parse().expect("Could not parse {x}")),
-                _ => panic!("Unexpected input!"),
+fn parse(input: &str, col1: &mut Vec<i32>, col2: &mut Vec<i32>) -> Result<(), AocError> {
+    for (line, l) in input.split('\n').enumerate() {
+        let cols: Vec<&str> = l.split_ascii_whitespace().collect();
+        if cols.len() != 2 {
+            return Err(AocError::parse_line(line + 1, "expected two columns"));
+        }
+        for (col, x) in [(&mut *col1, cols[0]), (&mut *col2, cols[1])] {
+            match x.parse() {
+                Ok(v) => col.push(v),
+                Err(_) => {
+                    return Err(AocError::parse_line(
+                        line + 1,
+                        format!("could not parse '{x}'"),
+                    ));
+                }
             }
         }
     }
+    Ok(())
 }
 
-pub fn run() -> (u64, u64) {
-    let mut a: Vec<i32> = Vec::new();
-    let mut b: Vec<i32> = Vec::new();
-
-    parse(INPUT, &mut a, &mut b);
-    let x = distance(&mut a, &mut b);
+pub(crate) static SOLVER: &dyn DynSolver = &Day1;
 
-    let y = similarity(&mut a, &mut b);
-    (x as u64, y as u64)
-}
+struct Day1;
 
-#[cfg(test)]
-mod tests {
-    use crate::y2024::day1::{distance, parse, similarity};
+impl Solver for Day1 {
+    const YEAR: u16 = 2024;
+    const DAY: u32 = 1;
 
-    const TEST_INPUT: &str = "3   4
-4   3
-2   5
-1   3
-3   9
-3   3";
+    type Input<'a> = (Vec<i32>, Vec<i32>);
 
-    #[test]
-    fn test_distance() {
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
         let mut a: Vec<i32> = Vec::new();
         let mut b: Vec<i32> = Vec::new();
-
-        parse(TEST_INPUT, &mut a, &mut b);
-        assert_eq!(11, distance(&mut a, &mut b))
+        parse(input, &mut a, &mut b)?;
+        Ok((a, b))
     }
 
-    #[test]
-    fn test_similarity() {
-        let mut a: Vec<i32> = Vec::new();
-        let mut b: Vec<i32> = Vec::new();
+    fn part1((a, b): &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(distance(&mut a.clone(), &mut b.clone()).into())
+    }
 
-        parse(TEST_INPUT, &mut a, &mut b);
-        assert_eq!(31, similarity(&mut a, &mut b))
+    fn part2((a, b): &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(similarity(&mut a.clone(), &mut b.clone()).into())
     }
 }
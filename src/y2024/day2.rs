@@ -1,18 +1,27 @@
-const INPUT: &str = include_str!("input/day2.txt");
+use std::{num::ParseIntError, str::FromStr};
+
+use crate::{
+    answer::Answer,
+    error::AocError,
+    helpers::parse::parse_lines,
+    solver::{Context, DynSolver, Solver},
+};
 
 struct Report(Vec<i32>);
 
-impl Report {
-    fn from(input: &str) -> Report {
-        let mut report: Vec<i32> = Vec::new();
-        report.extend(
-            input
-                .split_ascii_whitespace()
-                .map(|x| x.parse::<i32>().unwrap()),
-        );
-        Report(report)
+impl FromStr for Report {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let report = s
+            .split_ascii_whitespace()
+            .map(|x| x.parse::<i32>())
+            .collect::<Result<_, _>>()?;
+        Ok(Report(report))
     }
+}
 
+impl Report {
     fn is_safe(&self) -> bool {
         let differences = self
             .0
@@ -24,7 +33,7 @@ impl Report {
         let mut is_increasing: Option<bool> = Option::None;
         for x in differences {
             let absx = x.abs();
-            if absx < 1 || absx > 3 {
+            if !(1..=3).contains(&absx) {
                 return false;
             }
             match is_increasing {
@@ -60,7 +69,7 @@ impl Report {
             }
         }
 
-        return false;
+        false
     }
 
     fn count_safe_with_removal<'a>(reports: impl Iterator<Item = &'a Report>) -> usize {
@@ -68,32 +77,38 @@ impl Report {
     }
 }
 
-fn parse(input: &str) -> Vec<Report> {
-    let mut reports: Vec<Report> = Vec::new();
-    reports.extend(input.split('\n').map(|x| Report::from(x)));
-    reports
+fn parse(input: &str) -> Result<Vec<Report>, AocError> {
+    parse_lines(input).collect()
 }
 
-pub fn run() -> (u64, u64) {
-    let reports = parse(INPUT);
-    return (
-        Report::count_safe(reports.iter()) as u64,
-        Report::count_safe_with_removal(reports.iter()) as u64,
-    );
+pub(crate) static SOLVER: &dyn DynSolver = &Day2;
+
+struct Day2;
+
+impl Solver for Day2 {
+    const YEAR: u16 = 2024;
+    const DAY: u32 = 2;
+
+    type Input<'a> = Vec<Report>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse(input)
+    }
+
+    fn part1(reports: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(Report::count_safe(reports.iter()).into())
+    }
+
+    fn part2(reports: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(Report::count_safe_with_removal(reports.iter()).into())
+    }
 }
 
 #[cfg(test)]
 mod test {
     use rstest::rstest;
 
-    use crate::y2024::day2::{Report, parse};
-
-    const TEST_INPUT: &str = "7 6 4 2 1
-1 2 7 8 9
-9 7 6 2 1
-1 3 2 4 5
-8 6 4 4 1
-1 3 6 7 9";
+    use crate::y2024::day2::Report;
 
     #[rstest]
     #[case("7 6 4 2 1", true)]
@@ -103,16 +118,10 @@ mod test {
     #[case("8 6 4 4 1", false)]
     #[case("1 3 6 7 9", true)]
     fn test_is_safe(#[case] input: &str, #[case] expected: bool) {
-        let report = Report::from(input);
+        let report: Report = input.parse().unwrap();
         assert_eq!(expected, report.is_safe());
     }
 
-    #[test]
-    fn test_count_safe() {
-        let reports = parse(TEST_INPUT);
-        assert_eq!(2, Report::count_safe(reports.iter()));
-    }
-
     #[rstest]
     #[case("7 6 4 2 1", true)]
     #[case("1 2 7 8 9", false)]
@@ -121,13 +130,7 @@ mod test {
     #[case("8 6 4 4 1", true)]
     #[case("1 3 6 7 9", true)]
     fn test_is_safe_with_removal(#[case] input: &str, #[case] expected: bool) {
-        let report = Report::from(input);
+        let report: Report = input.parse().unwrap();
         assert_eq!(expected, report.is_safe_with_removal());
     }
-
-    #[test]
-    fn test_count_safe_with_removal() {
-        let reports = parse(TEST_INPUT);
-        assert_eq!(4, Report::count_safe_with_removal(reports.iter()));
-    }
 }
@@ -1,17 +1,39 @@
 use regex::Regex;
 
-const INPUT: &str = include_str!("input/day3.txt");
+use crate::{
+    answer::Answer,
+    error::AocError,
+    solver::{Context, DynSolver, Solver},
+};
 
-pub fn run() -> (u64, u64) {
-    (execute(INPUT), execute_with_conditions(INPUT))
+pub(crate) static SOLVER: &dyn DynSolver = &Day3;
+
+struct Day3;
+
+impl Solver for Day3 {
+    const YEAR: u16 = 2024;
+    const DAY: u32 = 3;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(execute(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(execute_with_conditions(input).into())
+    }
 }
 
 fn execute(input: &str) -> u64 {
     let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
-    return re
-        .captures_iter(input)
+    re.captures_iter(input)
         .map(|c| c[1].parse::<u64>().unwrap() * c[2].parse::<u64>().unwrap())
-        .sum();
+        .sum()
 }
 
 fn execute_with_conditions(input: &str) -> u64 {
@@ -33,26 +55,3 @@ fn execute_with_conditions(input: &str) -> u64 {
 
     sum
 }
-
-#[cfg(test)]
-mod test {
-    use crate::y2024::day3::{execute, execute_with_conditions};
-
-    #[test]
-    fn test_execute() {
-        const TEST_INPUT: &str =
-            "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
-
-        let output = execute(TEST_INPUT);
-        assert_eq!(161, output);
-    }
-
-    #[test]
-    fn test_execute_with_conditions() {
-        const TEST_INPUT: &str =
-            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
-
-        let output = execute_with_conditions(TEST_INPUT);
-        assert_eq!(48, output);
-    }
-}
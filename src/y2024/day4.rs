@@ -1,25 +1,43 @@
-use crate::helpers::grid::{Direction, Grid, Point};
+use crate::{
+    answer::Answer,
+    error::AocError,
+    helpers::grid::{Direction, Grid, Point},
+    solver::{Context, DynSolver, Solver},
+};
 
-const INPUT: &str = include_str!("input/day4.txt");
 const XMAS: &str = "XMAS";
 
-fn parse(input: &str) -> Grid<char> {
-    input.parse().unwrap()
+fn parse(input: &str) -> Result<Grid<char>, AocError> {
+    Ok(input.parse()?)
 }
 
-pub fn run() -> (u64, u64) {
-    let wordsearch = parse(INPUT);
-    (
-        wordsearch.find_words(XMAS) as u64,
-        wordsearch.find_x_mas() as u64,
-    )
+pub(crate) static SOLVER: &dyn DynSolver = &Day4;
+
+struct Day4;
+
+impl Solver for Day4 {
+    const YEAR: u16 = 2024;
+    const DAY: u32 = 4;
+
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse(input)
+    }
+
+    fn part1(wordsearch: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(wordsearch.find_words(XMAS).into())
+    }
+
+    fn part2(wordsearch: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(wordsearch.find_x_mas().into())
+    }
 }
 
 impl Grid<char> {
     fn find_words(&self, word: &str) -> u32 {
         let start = word.chars().next().expect("Empty word");
-        return self
-            .walk()
+        self.walk()
             .map(|(p, v)| {
                 if v == start {
                     self.find_words_at_point(&p, &word[1..])
@@ -27,13 +45,13 @@ impl Grid<char> {
                     0
                 }
             })
-            .sum();
+            .sum()
     }
 
     fn find_words_at_point(&self, p: &Point, word: &str) -> u32 {
-        return Direction::iter()
-            .filter(|d| self.direction_is_word(p, &d, word))
-            .count() as u32;
+        Direction::iter()
+            .filter(|d| self.direction_is_word(p, d, word))
+            .count() as u32
     }
 
     fn direction_is_word(&self, p: &Point, d: &Point, word: &str) -> bool {
@@ -43,13 +61,13 @@ impl Grid<char> {
         };
 
         let mut place = *p + *d;
-        if !self.get(place).is_some_and(|x| x == start) {
+        if self.get(place).is_none_or(|x| x != start) {
             return false;
         }
 
         for c in word[1..].chars() {
             place += *d;
-            if !self.get(place).is_some_and(|x| x == c) {
+            if self.get(place).is_none_or(|x| x != c) {
                 return false;
             }
         }
@@ -58,7 +76,7 @@ impl Grid<char> {
     }
 
     fn find_x_mas(&self) -> u32 {
-        return self.walk().filter(|(p, _)| self.is_x_mas(p)).count() as u32;
+        self.walk().filter(|(p, _)| self.is_x_mas(p)).count() as u32
     }
 
     fn is_x_mas(&self, p: &Point) -> bool {
@@ -84,37 +102,8 @@ impl Grid<char> {
             corners[3].unwrap(),
         ];
 
-        return ((corners[0] == 'M' && corners[2] == 'S')
-            || (corners[0] == 'S' && corners[2] == 'M'))
+        ((corners[0] == 'M' && corners[2] == 'S') || (corners[0] == 'S' && corners[2] == 'M'))
             && ((corners[1] == 'M' && corners[3] == 'S')
-                || (corners[1] == 'S' && corners[3] == 'M'));
-    }
-}
-
-#[cfg(test)]
-mod test {
-    use crate::y2024::day4::{XMAS, parse};
-
-    const TEST_INPUT: &str = "MMMSXXMASM
-MSAMXMSMSA
-AMXSXMAAMM
-MSAMASMSMX
-XMASAMXAMM
-XXAMMXXAMA
-SMSMSASXSS
-SAXAMASAAA
-MAMMMXMMMM
-MXMXAXMASX";
-
-    #[test]
-    fn test_find_xmas_words() {
-        let wordsearch = parse(TEST_INPUT);
-        assert_eq!(18, wordsearch.find_words(XMAS));
-    }
-
-    #[test]
-    fn test_find_x_mas() {
-        let wordsearch = parse(TEST_INPUT);
-        assert_eq!(9, wordsearch.find_x_mas());
+                || (corners[1] == 'S' && corners[3] == 'M'))
     }
 }
@@ -1,47 +1,66 @@
 use std::collections::{HashMap, HashSet};
 
-const INPUT: &str = include_str!("input/day5.txt");
-
-pub fn run() -> (u64, u64) {
-    let (rules, updates) = parse(INPUT);
-    return (
-        sum_middle_valid_updates(&rules, &updates) as u64,
-        0, // sum_middle_fixed_updates(&rules, &mut updates) as u64,
-    );
+use crate::{
+    answer::Answer,
+    error::AocError,
+    solver::{Context, DynSolver, Solver},
+};
+
+pub(crate) static SOLVER: &dyn DynSolver = &Day5;
+
+struct Day5;
+
+impl Solver for Day5 {
+    const YEAR: u16 = 2024;
+    const DAY: u32 = 5;
+
+    type Input<'a> = (Rules, Vec<Vec<u32>>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse(input)
+    }
+
+    fn part1((rules, updates): &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(sum_middle_valid_updates(rules, updates).into())
+    }
+
+    fn part2(_: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(Answer::NotImplemented) // sum_middle_fixed_updates(rules, &mut updates.clone()).into()
+    }
 }
 
-fn parse(input: &str) -> (Rules, Vec<Vec<u32>>) {
-    let mut lines = input.split('\n');
-    let mut line = lines.next();
+fn parse(input: &str) -> Result<(Rules, Vec<Vec<u32>>), AocError> {
+    let mut lines = input.split('\n').enumerate();
     let mut rules = Rules::new();
-    while line.is_some_and(|l| !l.trim().is_empty()) {
-        rules.parse_and_add_rule(line.unwrap().trim());
-        line = lines.next();
+    for (idx, line) in lines.by_ref() {
+        if line.trim().is_empty() {
+            break;
+        }
+        rules
+            .parse_and_add_rule(line.trim())
+            .map_err(|e| AocError::parse_line(idx + 1, format!("{e:?}")))?;
     }
 
-    line = lines.next();
     let mut updates: Vec<Vec<u32>> = Vec::new();
-    while line.is_some() {
-        let mut update: Vec<u32> = Vec::new();
-        update.extend(
-            line.unwrap()
-                .trim()
-                .split(',')
-                .map(|x| x.parse::<u32>().expect("Failed to read page")),
-        );
+    for (idx, line) in lines {
+        let update = line
+            .trim()
+            .split(',')
+            .map(|x| x.parse::<u32>())
+            .collect::<Result<_, _>>()
+            .map_err(|_| AocError::parse_line(idx + 1, "failed to read page"))?;
         updates.push(update);
-        line = lines.next()
     }
 
-    return (rules, updates);
+    Ok((rules, updates))
 }
 
-fn find_middle(update: &Vec<u32>) -> u32 {
+fn find_middle(update: &[u32]) -> u32 {
     update[update.len() / 2]
 }
 
-fn sum_middle_valid_updates(rules: &Rules, updates: &Vec<Vec<u32>>) -> u32 {
-    return updates
+fn sum_middle_valid_updates(rules: &Rules, updates: &[Vec<u32>]) -> u32 {
+    updates
         .iter()
         .map(|update| {
             if rules.validate(update) {
@@ -50,7 +69,7 @@ fn sum_middle_valid_updates(rules: &Rules, updates: &Vec<Vec<u32>>) -> u32 {
                 0
             }
         })
-        .sum();
+        .sum()
 }
 
 // fn sum_middle_fixed_updates(rules: &Rules, updates: &mut Vec<Vec<u32>>) -> u32 {
@@ -66,6 +85,12 @@ fn sum_middle_valid_updates(rules: &Rules, updates: &Vec<Vec<u32>>) -> u32 {
 //         .sum();
 // }
 
+#[derive(Debug)]
+enum RuleParseError {
+    MissingPage,
+    NotInteger,
+}
+
 struct Rules {
     rules_before: HashMap<u32, HashSet<u32>>,
     rules_after: HashMap<u32, HashSet<u32>>,
@@ -80,32 +105,23 @@ impl Rules {
     }
 
     fn add_rule(&mut self, before: u32, after: u32) {
-        self.rules_before
-            .entry(before)
-            .or_insert(HashSet::<u32>::new())
-            .insert(after);
-        self.rules_after
-            .entry(after)
-            .or_insert(HashSet::<u32>::new())
-            .insert(before);
+        self.rules_before.entry(before).or_default().insert(after);
+        self.rules_after.entry(after).or_default().insert(before);
     }
 
-    pub fn parse_and_add_rule(&mut self, input: &str) {
+    pub fn parse_and_add_rule(&mut self, input: &str) -> Result<(), RuleParseError> {
         let mut parts = input.split('|');
-        let before: u32 = parts
-            .next()
-            .expect("Failed to parse line")
-            .parse()
-            .expect("Failed to parse line");
-        let after: u32 = parts
-            .next()
-            .expect("Failed to parse line")
-            .parse()
-            .expect("Failed to parse line");
+        let mut page = || match parts.next() {
+            Some(p) => p.parse::<u32>().map_err(|_| RuleParseError::NotInteger),
+            None => Err(RuleParseError::MissingPage),
+        };
+        let before = page()?;
+        let after = page()?;
         self.add_rule(before, after);
+        Ok(())
     }
 
-    pub fn validate(&self, input: &Vec<u32>) -> bool {
+    pub fn validate(&self, input: &[u32]) -> bool {
         let mut visited: HashSet<u32> = HashSet::new();
         for x in input.iter() {
             if self
@@ -156,62 +172,16 @@ impl Rules {
 mod tests {
     use rstest::rstest;
 
-    use crate::y2024::day5::{parse, sum_middle_valid_updates};
-
-    const TEST_INPUT: &str = "47|53
-97|13
-97|61
-97|47
-75|29
-61|13
-75|53
-29|13
-97|29
-53|29
-61|53
-97|53
-61|29
-47|13
-75|47
-97|75
-47|61
-75|61
-47|29
-75|13
-53|13
-
-75,47,61,53,29
-97,61,53,29,13
-75,29,13
-75,97,47,61,53
-61,13,29
-97,13,75,29,47";
+    use crate::y2024::day5::parse;
 
     #[rstest]
-    #[case(0, true)]
-    #[case(1, true)]
-    #[case(2, true)]
-    #[case(3, false)]
-    #[case(4, false)]
-    #[case(5, false)]
-    fn test_validate(#[case] index: usize, #[case] expected: bool) {
-        let (rules, updates) = parse(TEST_INPUT);
-        assert_eq!(expected, rules.validate(&updates[index]));
+    #[case("75,47,61", true)]
+    #[case("97,75,47", true)]
+    #[case("61,47", false)]
+    #[case("75,97", false)]
+    fn test_validate(#[case] update: &str, #[case] expected: bool) {
+        let input = format!("75|47\n47|61\n61|53\n97|75\n\n{update}");
+        let (rules, updates) = parse(&input).unwrap();
+        assert_eq!(expected, rules.validate(&updates[0]));
     }
-
-    #[test]
-    fn test_sum_middle_valid_updates() {
-        let (rules, updates) = parse(TEST_INPUT);
-        assert_eq!(143, sum_middle_valid_updates(&rules, &updates))
-    }
-
-    // #[rstest]
-    // #[case(3, &[97, 75, 47, 61, 53])]
-    // #[case(4, &[61, 29, 13])]
-    // #[case(5, &[97, 75, 47, 29, 13])]
-    // fn test_fix(#[case] index: usize, #[case] expected: &[u32]) {
-    //     let (rules, mut updates) = parse(TEST_INPUT);
-    //     let expected = Vec::from(expected);
-    //     assert_eq!(expected, rules.fix(&mut updates[index]));
-    // }
 }
@@ -1,15 +1,33 @@
 use std::str::FromStr;
 
-use crate::helpers::parse::parse_lines;
+use crate::{
+    answer::Answer,
+    error::AocError,
+    helpers::parse::parse_lines,
+    solver::{Context, DynSolver, Solver},
+};
 
-const INPUT: &str = include_str!("input/day1.txt");
+pub(crate) static SOLVER: &dyn DynSolver = &Day1;
 
-pub fn run() -> (u64, u64) {
-    let rotations: Vec<Rotation> = parse_lines(INPUT).collect();
-    (
-        calculate_password(rotations.iter()),
-        calculate_password_secure(rotations.iter()),
-    )
+struct Day1;
+
+impl Solver for Day1 {
+    const YEAR: u16 = 2025;
+    const DAY: u32 = 1;
+
+    type Input<'a> = Vec<Rotation>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_lines(input).collect()
+    }
+
+    fn part1(rotations: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(calculate_password(rotations.iter()).into())
+    }
+
+    fn part2(rotations: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(calculate_password_secure(rotations.iter()).into())
+    }
 }
 
 #[derive(Debug)]
@@ -35,12 +53,12 @@ impl FromStr for Rotation {
     type Err = ParseRotationError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let direction = match &s[0..1] {
-            "L" => Direction::Left,
-            "R" => Direction::Right,
+        let direction = match s.get(0..1) {
+            Some("L") => Direction::Left,
+            Some("R") => Direction::Right,
             _ => return Err(ParseRotationError::InvalidDirection),
         };
-        let full_distance: u32 = match (&s[1..]).parse::<u32>() {
+        let full_distance: u32 = match s[1..].parse::<u32>() {
             Ok(r) => r,
             Err(_) => return Err(ParseRotationError::UnparseableDistance),
         };
@@ -77,9 +95,9 @@ impl Rotation {
         if pos >= 100 || pos <= 0 {
             count += 1;
         }
-        pos = pos % 100;
+        pos %= 100;
         if pos < 0 {
-            pos = pos + 100;
+            pos += 100;
         }
         (pos.try_into().unwrap(), count)
     }
@@ -107,33 +125,3 @@ fn calculate_password_secure<'a>(rotations: impl Iterator<Item = &'a Rotation>)
     }
     password
 }
-
-#[cfg(test)]
-mod test {
-    use crate::y2025::day1::{
-        Rotation, calculate_password, calculate_password_secure, parse_lines,
-    };
-
-    const TEST_INPUT: &str = "L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82";
-
-    #[test]
-    fn test_calculate_password() {
-        let rotations: Vec<Rotation> = parse_lines(TEST_INPUT).collect();
-        assert_eq!(3, calculate_password(rotations.iter()));
-    }
-
-    #[test]
-    fn test_calculate_password_secure() {
-        let rotations: Vec<Rotation> = parse_lines(TEST_INPUT).collect();
-        assert_eq!(6, calculate_password_secure(rotations.iter()));
-    }
-}
@@ -3,43 +3,67 @@ use std::str::FromStr;
 use itertools::Itertools;
 use regex::Regex;
 
-use crate::helpers::parse::parse_lines;
+use crate::{
+    answer::Answer,
+    error::AocError,
+    helpers::parse::parse_lines,
+    solver::{Context, DynSolver, Solver},
+};
 
-const INPUT: &str = include_str!("input/day10.txt");
+pub(crate) static SOLVER: &dyn DynSolver = &Day10;
 
-pub fn run() -> (u64, u64) {
-    let mut machines: Vec<Machine> = Machines::parse(INPUT);
-    (
-        machines.sum_min_presses(),
-        0, // machines.sum_min_presses_joltage(),
-    )
+struct Day10;
+
+impl Solver for Day10 {
+    const YEAR: u16 = 2025;
+    const DAY: u32 = 10;
+
+    type Input<'a> = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Machines::parse(input)
+    }
+
+    fn part1(machines: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        let presses = machines
+            .clone()
+            .sum_min_presses()
+            .ok_or_else(|| AocError::solver("a machine can never reach its target lights"))?;
+        Ok(presses.into())
+    }
+
+    fn part2(machines: &Self::Input<'_>, ctx: &Context) -> Result<Answer, AocError> {
+        Ok(machines.clone().sum_min_presses_joltage(ctx)?.into())
+    }
 }
 
 trait Machines {
-    fn parse(input: &str) -> Self;
-    fn sum_min_presses(&mut self) -> u64;
-    fn sum_min_presses_joltage(&mut self) -> u64;
+    fn parse(input: &str) -> Result<Self, AocError>
+    where
+        Self: Sized;
+    fn sum_min_presses(&mut self) -> Option<u64>;
+    fn sum_min_presses_joltage(&mut self, ctx: &Context) -> Result<u64, AocError>;
 }
 
 impl Machines for Vec<Machine> {
-    fn parse(input: &str) -> Vec<Machine> {
+    fn parse(input: &str) -> Result<Vec<Machine>, AocError> {
         parse_lines(input).collect()
     }
 
-    fn sum_min_presses(&mut self) -> u64 {
+    fn sum_min_presses(&mut self) -> Option<u64> {
         self.iter_mut()
-            .map(|m| m.find_min_presses().unwrap() as u64)
+            .map(|m| m.find_min_presses().map(|x| x as u64))
             .sum()
     }
 
-    fn sum_min_presses_joltage(&mut self) -> u64 {
+    fn sum_min_presses_joltage(&mut self, ctx: &Context) -> Result<u64, AocError> {
         self.iter_mut()
-            .map(|m| m.find_min_presses_joltage().unwrap() as u64)
+            .map(|m| m.find_min_presses_joltage(ctx).map(|x| x as u64))
             .sum()
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Machine {
     lights: u16,
     target_lights: u16,
@@ -50,7 +74,7 @@ struct Machine {
 
 impl Machine {
     fn push_button(&mut self, idx: usize) {
-        self.lights = self.lights ^ self.buttons[idx];
+        self.lights ^= self.buttons[idx];
     }
 
     fn push_button_joltage(&mut self, idx: usize) {
@@ -59,14 +83,13 @@ impl Machine {
             if button & 1 == 1 {
                 self.joltages[i] += 1
             }
-            button = button >> 1;
+            button >>= 1;
         }
     }
 
     fn reset(&mut self) {
         self.lights = 0;
-        let mut new_joltages: Vec<u32> = Vec::with_capacity(self.joltages.len());
-        new_joltages.resize(self.joltages.len(), 0);
+        let new_joltages: Vec<u32> = vec![0; self.joltages.len()];
         self.joltages = new_joltages;
     }
 
@@ -93,15 +116,18 @@ impl Machine {
         None
     }
 
-    fn find_min_presses_joltage(&mut self) -> Option<usize> {
+    /// Searches ever longer press sequences, so on real inputs it only ends when `ctx` is
+    /// cancelled.
+    fn find_min_presses_joltage(&mut self, ctx: &Context) -> Result<usize, AocError> {
         let mut idx: usize = 0;
         loop {
             for presses in (0..self.buttons.len()).combinations_with_replacement(idx) {
+                ctx.check_cancelled()?;
                 for b in presses {
                     self.push_button_joltage(b);
                 }
                 if self.is_at_target_joltage() {
-                    return Some(idx);
+                    return Ok(idx);
                 }
                 self.reset();
             }
@@ -113,6 +139,8 @@ impl Machine {
 #[derive(Debug)]
 enum MachineParseErr {
     InvalidMachine,
+    NotInteger,
+    TooManyLights,
 }
 
 impl FromStr for Machine {
@@ -123,16 +151,17 @@ impl FromStr for Machine {
             r"^\[(?<lights>[\.#]+)\] (?<buttons>[\(\)\d, ]+\) )+\{(?<joltages>[\d,]+)\}$",
         )
         .unwrap();
-        let captures = re.captures(s);
-        if captures.is_none() {
+        let Some(captures) = re.captures(s) else {
             return Err(MachineParseErr::InvalidMachine);
+        };
+        let target_lights = captures.name("lights").unwrap().as_str();
+        if target_lights.len() > u16::BITS as usize {
+            return Err(MachineParseErr::TooManyLights);
         }
-        let captures = captures.unwrap();
-        let target_lights = captures.name("lights").unwrap().as_str().chars();
         let mut target: u16 = 0;
-        for (idx, c) in target_lights.enumerate() {
+        for (idx, c) in target_lights.chars().enumerate() {
             if c == '#' {
-                target = target | (1 << idx);
+                target |= 1 << idx;
             }
         }
 
@@ -141,14 +170,12 @@ impl FromStr for Machine {
         let mut buttons: Vec<u16> = Vec::new();
         for cap in buttons_re.captures_iter(buttons_inp) {
             let mut b: u16 = 0;
-            for c in cap
-                .name("button")
-                .unwrap()
-                .as_str()
-                .split(',')
-                .map(|x| x.parse::<u16>().unwrap())
-            {
-                b = b | (1 << c);
+            for c in cap.name("button").unwrap().as_str().split(',') {
+                let c: u32 = c.parse().map_err(|_| MachineParseErr::NotInteger)?;
+                if c >= u16::BITS {
+                    return Err(MachineParseErr::TooManyLights);
+                }
+                b |= 1 << c;
             }
             buttons.push(b);
         }
@@ -158,11 +185,10 @@ impl FromStr for Machine {
             .unwrap()
             .as_str()
             .split(',')
-            .map(|x| x.parse::<u32>().unwrap())
-            .collect();
+            .map(|x| x.parse::<u32>().map_err(|_| MachineParseErr::NotInteger))
+            .collect::<Result<_, _>>()?;
 
-        let mut joltages: Vec<u32> = Vec::with_capacity(target_joltages.len());
-        joltages.resize(target_joltages.len(), 0);
+        let joltages: Vec<u32> = vec![0; target_joltages.len()];
 
         Ok(Machine {
             lights: 0,
@@ -178,11 +204,7 @@ impl FromStr for Machine {
 mod test {
     use rstest::rstest;
 
-    use crate::y2025::day10::{Machine, Machines};
-
-    const TEST_INPUT: &str = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
-[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
-[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+    use crate::{solver::Context, y2025::day10::Machine};
 
     #[test]
     fn test_parse() {
@@ -224,12 +246,6 @@ mod test {
         assert_eq!(min_presses, machine.find_min_presses().unwrap());
     }
 
-    #[test]
-    fn test_sum_min_presses() {
-        let mut machines: Vec<Machine> = Machines::parse(TEST_INPUT);
-        assert_eq!(7, machines.sum_min_presses());
-    }
-
     #[test]
     fn test_push_button_joltage() {
         let inp = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}";
@@ -246,12 +262,11 @@ mod test {
     #[case("[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}", 11)]
     fn test_find_min_presses_joltage(#[case] input: &str, #[case] min_presses: usize) {
         let mut machine: Machine = input.parse().unwrap();
-        assert_eq!(min_presses, machine.find_min_presses_joltage().unwrap());
-    }
-
-    #[test]
-    fn test_sum_min_presses_joltage() {
-        let mut machines: Vec<Machine> = Machines::parse(TEST_INPUT);
-        assert_eq!(33, machines.sum_min_presses_joltage());
+        assert_eq!(
+            min_presses,
+            machine
+                .find_min_presses_joltage(&Context::default())
+                .unwrap()
+        );
     }
 }
@@ -1,22 +1,47 @@
-use crate::helpers::dag::DirectedAcyclicGraph;
+use crate::{
+    answer::Answer,
+    error::AocError,
+    helpers::dag::DirectedAcyclicGraph,
+    solver::{Context, DynSolver, Solver},
+};
 
-const INPUT: &str = include_str!("input/day11.txt");
+pub(crate) static SOLVER: &dyn DynSolver = &Day11;
 
-pub fn run() -> (u64, u64) {
-    let tree: DirectedAcyclicGraph<&str> = parse(INPUT);
-    (tree.count_paths_you_to_out() as u64, 0)
+struct Day11;
+
+impl Solver for Day11 {
+    const YEAR: u16 = 2025;
+    const DAY: u32 = 11;
+
+    type Input<'a> = DirectedAcyclicGraph<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse(input)
+    }
+
+    fn part1(tree: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(tree.count_paths_you_to_out().into())
+    }
+
+    fn part2(_: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(Answer::NotImplemented)
+    }
 }
 
-fn parse(input: &str) -> DirectedAcyclicGraph<&str> {
+fn parse(input: &str) -> Result<DirectedAcyclicGraph<&str>, AocError> {
     let mut tree = DirectedAcyclicGraph::new();
-    for line in input.split('\n') {
+    for (idx, line) in input.split('\n').enumerate() {
         let line = line.trim();
-        let parts: Vec<&str> = line.split(':').collect();
-        let val = parts[0];
-        let children: Vec<&str> = parts[1].split(' ').filter(|x| !x.is_empty()).collect();
+        let Some((val, children)) = line.split_once(':') else {
+            return Err(AocError::parse_line(
+                idx + 1,
+                format!("missing ':' in '{line}'"),
+            ));
+        };
+        let children: Vec<&str> = children.split(' ').filter(|x| !x.is_empty()).collect();
         tree.insert(val, children);
     }
-    tree
+    Ok(tree)
 }
 
 trait Cables {
@@ -31,31 +56,14 @@ impl Cables for DirectedAcyclicGraph<&str> {
 
 #[cfg(test)]
 mod test {
-    use crate::y2025::day11::{Cables, parse};
-
-    const TEST_INPUT: &str = "aaa: you hhh
-you: bbb ccc
-bbb: ddd eee
-ccc: ddd eee fff
-ddd: ggg
-eee: out
-fff: out
-ggg: out
-hhh: ccc fff iii
-iii: out";
+    use crate::y2025::day11::parse;
 
     #[test]
     fn test_parse() {
-        let tree = parse(TEST_INPUT);
+        let tree = parse("you: bbb ccc\nbbb: out\nccc: out").unwrap();
         assert!(
             tree.get_children(&"you")
                 .is_some_and(|x| x.contains(&"bbb"))
         );
     }
-
-    #[test]
-    fn test_count_paths() {
-        let tree = parse(TEST_INPUT);
-        assert_eq!(5, tree.count_paths_you_to_out());
-    }
 }
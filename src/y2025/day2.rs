@@ -1,24 +1,56 @@
 use std::str::FromStr;
 
-use crate::helpers::parse::parse_split;
+use crate::{
+    answer::Answer,
+    error::AocError,
+    solver::{Context, DynSolver, Solver},
+};
 
-const INPUT: &str = include_str!("input/day2.txt");
+pub(crate) static SOLVER: &dyn DynSolver = &Day2;
 
-pub fn run() -> (u64, u64) {
-    (sum_invalid_ids(INPUT), sum_really_invalid_ids(INPUT))
+struct Day2;
+
+impl Solver for Day2 {
+    const YEAR: u16 = 2025;
+    const DAY: u32 = 2;
+
+    type Input<'a> = Vec<ProductIdRange>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse(input)
+    }
+
+    fn part1(ranges: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(sum_invalid_ids(ranges).into())
+    }
+
+    fn part2(ranges: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(sum_really_invalid_ids(ranges).into())
+    }
 }
 
-fn parse(input: &str) -> impl Iterator<Item = ProductIdRange> {
-    parse_split(input.split(','))
+fn parse(input: &str) -> Result<Vec<ProductIdRange>, AocError> {
+    let mut column = 1;
+    input
+        .split(',')
+        .map(|r| {
+            let range = r
+                .trim()
+                .parse()
+                .map_err(|e| AocError::parse_at(1, column, format!("'{r}': {e:?}")));
+            column += r.chars().count() + 1;
+            range
+        })
+        .collect()
 }
 
-fn sum_invalid_ids(input: &str) -> u64 {
-    parse(input).map(|r| r.list_invalid_ids()).flatten().sum()
+fn sum_invalid_ids(ranges: &[ProductIdRange]) -> u64 {
+    ranges.iter().flat_map(|r| r.list_invalid_ids()).sum()
 }
 
-fn sum_really_invalid_ids(input: &str) -> u64 {
+fn sum_really_invalid_ids(ranges: &[ProductIdRange]) -> u64 {
     let mut s: u64 = 0;
-    for r in parse(input) {
+    for r in ranges {
         s += r.list_really_invalid_ids().sum::<u64>();
     }
     s
@@ -29,7 +61,7 @@ fn count_digits(inp: u64) -> u32 {
     let mut x = inp / 10;
     while x > 0 {
         c += 1;
-        x = x / 10;
+        x /= 10;
     }
     c
 }
@@ -78,12 +110,12 @@ impl ProductIdRange {
 
     fn nearest_candidate(x: u64) -> (u64, u32) {
         let d = count_digits(x);
-        if d % 2 == 0 {
+        if d.is_multiple_of(2) {
             let exp = d / 2;
             return (x / (Self::BASE.pow(exp)), exp);
         }
         let exp = (d / 2) + 1;
-        return (Self::BASE.pow(exp - 1), exp);
+        (Self::BASE.pow(exp - 1), exp)
     }
 
     fn min_candidate(&self) -> (u64, u32) {
@@ -140,16 +172,14 @@ fn is_invalid_id(x: u64) -> bool {
         }
         idx += 1;
     }
-    return false;
+    false
 }
 
 #[cfg(test)]
 mod test {
     use rstest::rstest;
 
-    use crate::y2025::day2::{ProductIdRange, sum_invalid_ids, sum_really_invalid_ids};
-
-    const TEST_INPUT: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+    use crate::y2025::day2::ProductIdRange;
 
     #[rstest]
     #[case("11-22", &[11, 22])]
@@ -165,11 +195,6 @@ mod test {
         assert_eq!(expected, range.list_invalid_ids());
     }
 
-    #[test]
-    fn test_sum_invalid_ids() {
-        assert_eq!(1227775554, sum_invalid_ids(TEST_INPUT));
-    }
-
     #[rstest]
     #[case("11-22", &[11, 22])]
     #[case("95-115", &[99, 111])]
@@ -186,9 +211,4 @@ mod test {
         let actual: Vec<u64> = range.list_really_invalid_ids().collect();
         assert_eq!(expected, &actual);
     }
-
-    #[test]
-    fn test_sum_really_invalid_ids() {
-        assert_eq!(4174379265, sum_really_invalid_ids(TEST_INPUT));
-    }
 }
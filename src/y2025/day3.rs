@@ -1,27 +1,60 @@
-const INPUT: &str = include_str!("input/day3.txt");
-const SAFE_NUM_BATTERIES: usize = 2;
-const UNSAFE_NUM_BATTERIES: usize = 12;
-
-pub fn run() -> (u64, u64) {
-    (sum_joltage_safe(INPUT), sum_joltage_unsafe(INPUT))
-}
+use crate::{
+    answer::Answer,
+    error::AocError,
+    solver::{Context, DynSolver, Param, Solver},
+};
+
+pub(crate) static SOLVER: &dyn DynSolver = &Day3;
+
+struct Day3;
+
+impl Solver for Day3 {
+    const YEAR: u16 = 2025;
+    const DAY: u32 = 3;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "safe_batteries",
+            default: "2",
+            help: "batteries turned on per bank in part 1",
+        },
+        Param {
+            name: "unsafe_batteries",
+            default: "12",
+            help: "batteries turned on per bank in part 2",
+        },
+    ];
+
+    type Input<'a> = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse(input)
+    }
 
-fn sum_joltage_safe(input: &str) -> u64 {
-    sum_joltage(input, SAFE_NUM_BATTERIES)
-}
+    fn part1(banks: &Self::Input<'_>, ctx: &Context) -> Result<Answer, AocError> {
+        Ok(sum_joltage(banks, ctx.param("safe_batteries")?).into())
+    }
 
-fn sum_joltage_unsafe(input: &str) -> u64 {
-    sum_joltage(input, UNSAFE_NUM_BATTERIES)
+    fn part2(banks: &Self::Input<'_>, ctx: &Context) -> Result<Answer, AocError> {
+        Ok(sum_joltage(banks, ctx.param("unsafe_batteries")?).into())
+    }
 }
 
-fn sum_joltage(input: &str, num_batteries: usize) -> u64 {
+fn parse(input: &str) -> Result<Vec<Vec<u32>>, AocError> {
     input
         .split('\n')
-        .map(|l| parse_bank(l.trim()))
-        .map(|r| {
-            let bank: Vec<u32> = r.collect();
-            calculate_joltage(&bank, num_batteries)
+        .enumerate()
+        .map(|(line, l)| {
+            parse_bank(l.trim())
+                .map_err(|col| AocError::parse_at(line + 1, col + 1, "expected a digit"))
         })
+        .collect()
+}
+
+fn sum_joltage(banks: &[Vec<u32>], num_batteries: usize) -> u64 {
+    banks
+        .iter()
+        .map(|bank| calculate_joltage(bank, num_batteries))
         .sum()
 }
 
@@ -56,7 +89,7 @@ fn calculate_joltage(bank: &[u32], num_batteries: usize) -> u64 {
 fn concat_digits(digits: &mut Vec<u32>) -> u64 {
     let mut base: u64 = 1;
     let mut res: u64 = 0;
-    while digits.len() > 0 {
+    while !digits.is_empty() {
         res += (digits.pop().unwrap() as u64) * base;
         base *= 10;
     }
@@ -64,23 +97,23 @@ fn concat_digits(digits: &mut Vec<u32>) -> u64 {
     res
 }
 
-fn parse_bank(input: &str) -> impl Iterator<Item = u32> {
-    input.chars().map(|c| c.to_digit(10).unwrap())
+/// Reads a bank of single-digit batteries, failing with the index of the first non-digit.
+fn parse_bank(input: &str) -> Result<Vec<u32>, usize> {
+    input
+        .chars()
+        .enumerate()
+        .map(|(idx, c)| c.to_digit(10).ok_or(idx))
+        .collect()
 }
 
 #[cfg(test)]
 mod test {
     use rstest::rstest;
 
-    use crate::y2025::day3::{
-        SAFE_NUM_BATTERIES, UNSAFE_NUM_BATTERIES, calculate_joltage, parse_bank, sum_joltage_safe,
-        sum_joltage_unsafe,
-    };
+    use crate::y2025::day3::{calculate_joltage, parse_bank};
 
-    const TEST_INPUT: &str = "987654321111111
-811111111111119
-234234234234278
-818181911112111";
+    const SAFE_NUM_BATTERIES: usize = 2;
+    const UNSAFE_NUM_BATTERIES: usize = 12;
 
     #[rstest]
     #[case("987654321111111", 98)]
@@ -88,7 +121,7 @@ mod test {
     #[case("234234234234278", 78)]
     #[case("818181911112111", 92)]
     fn test_calculate_joltage_safe(#[case] inp: &str, #[case] expected: u64) {
-        let bank: Vec<u32> = parse_bank(inp).collect();
+        let bank = parse_bank(inp).unwrap();
         assert_eq!(expected, calculate_joltage(&bank, SAFE_NUM_BATTERIES))
     }
 
@@ -98,17 +131,7 @@ mod test {
     #[case("234234234234278", 434234234278)]
     #[case("818181911112111", 888911112111)]
     fn test_calculate_joltage_unsafe(#[case] inp: &str, #[case] expected: u64) {
-        let bank: Vec<u32> = parse_bank(inp).collect();
+        let bank = parse_bank(inp).unwrap();
         assert_eq!(expected, calculate_joltage(&bank, UNSAFE_NUM_BATTERIES))
     }
-
-    #[test]
-    fn test_sum_joltage_safe() {
-        assert_eq!(357, sum_joltage_safe(TEST_INPUT))
-    }
-
-    #[test]
-    fn test_sum_joltage_unsafe() {
-        assert_eq!(3121910778619, sum_joltage_unsafe(TEST_INPUT))
-    }
 }
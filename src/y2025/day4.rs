@@ -1,17 +1,35 @@
-use crate::helpers::grid::{Grid, Point};
+use crate::{
+    answer::Answer,
+    error::AocError,
+    helpers::grid::{Grid, Neighbourhood, Point},
+    solver::{Context, DynSolver, Solver},
+};
 
-const INPUT: &str = include_str!("input/day4.txt");
+pub(crate) static SOLVER: &dyn DynSolver = &Day4;
 
-pub fn run() -> (u64, u64) {
-    let mut paper_trail = parse(INPUT);
-    (
-        paper_trail.count_accessible_rolls() as u64,
-        paper_trail.remove_accessible_rolls() as u64,
-    )
+struct Day4;
+
+impl Solver for Day4 {
+    const YEAR: u16 = 2025;
+    const DAY: u32 = 4;
+
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse(input)
+    }
+
+    fn part1(paper_trail: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(paper_trail.count_accessible_rolls().into())
+    }
+
+    fn part2(paper_trail: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(paper_trail.clone().remove_accessible_rolls().into())
+    }
 }
 
-fn parse(input: &str) -> impl PaperTrail {
-    input.parse::<Grid<char>>().unwrap()
+fn parse(input: &str) -> Result<Grid<char>, AocError> {
+    Ok(input.parse::<Grid<char>>()?)
 }
 
 trait PaperTrail {
@@ -37,9 +55,7 @@ impl PaperTrail for Grid<char> {
     }
 
     fn iter_adjacent_rolls(&self, p: Point) -> impl Iterator<Item = Point> {
-        self.iter_adjacent(p)
-            .filter(|(q, _)| self.is_roll(*q))
-            .map(|(q, _)| q)
+        self.neighbours_matching(p, Neighbourhood::Moore, |v| v == Self::ROLL)
     }
 
     fn is_accessible_roll(&self, p: Point) -> bool {
@@ -71,30 +87,3 @@ impl PaperTrail for Grid<char> {
         count
     }
 }
-
-#[cfg(test)]
-mod test {
-    use crate::y2025::day4::{PaperTrail, parse};
-
-    const TEST_INPUT: &str = "..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.";
-
-    #[test]
-    fn test_count_accessible_rolls() {
-        assert_eq!(13, parse(TEST_INPUT).count_accessible_rolls())
-    }
-
-    #[test]
-    fn test_remove_accessible_rolls() {
-        let mut paper_trail = parse(TEST_INPUT);
-        assert_eq!(43, paper_trail.remove_accessible_rolls());
-    }
-}
@@ -1,25 +1,52 @@
 use std::str::FromStr;
 
-const INPUT: &str = include_str!("input/day5.txt");
-
-pub fn run() -> (u64, u64) {
-    let (ranges, ingredients) = parse(INPUT);
-    (
-        ranges.count_fresh(&ingredients) as u64,
-        ranges.total_fresh(),
-    )
+use crate::{
+    answer::Answer,
+    error::AocError,
+    helpers::parse::parse_split,
+    solver::{Context, DynSolver, Solver},
+};
+
+pub(crate) static SOLVER: &dyn DynSolver = &Day5;
+
+struct Day5;
+
+impl Solver for Day5 {
+    const YEAR: u16 = 2025;
+    const DAY: u32 = 5;
+
+    type Input<'a> = (Vec<Range>, Vec<u64>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse(input)
+    }
+
+    fn part1((ranges, ingredients): &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(ranges.count_fresh(ingredients).into())
+    }
+
+    fn part2((ranges, _): &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(ranges.total_fresh().into())
+    }
 }
 
-fn parse(inp: &str) -> (impl FreshRanges, Vec<u64>) {
-    let mut lines = inp.split('\n');
+fn parse(inp: &str) -> Result<(Vec<Range>, Vec<u64>), AocError> {
+    let mut lines = inp.split('\n').enumerate();
     let ranges: Vec<Range> = FreshRanges::parse(
         lines
             .by_ref()
+            .map(|(_, line)| line)
             .take_while(|x| !x.trim().is_empty())
             .map(|line| line.trim()),
-    );
-    let ingredients: Vec<u64> = lines.map(|line| line.trim().parse().unwrap()).collect();
-    (ranges, ingredients)
+    )?;
+    let ingredients: Vec<u64> = lines
+        .map(|(idx, line)| {
+            line.trim()
+                .parse()
+                .map_err(|_| AocError::parse_line(idx + 1, format!("invalid ingredient '{line}'")))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((ranges, ingredients))
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
@@ -68,7 +95,9 @@ impl Range {
 }
 
 trait FreshRanges {
-    fn parse<'a>(inp: impl Iterator<Item = &'a str>) -> Self;
+    fn parse<'a>(inp: impl Iterator<Item = &'a str>) -> Result<Self, AocError>
+    where
+        Self: Sized;
     fn collapse(&mut self) -> Self;
     fn is_fresh(&self, x: u64) -> bool;
     fn count_fresh(&self, ingredients: &[u64]) -> usize;
@@ -76,9 +105,9 @@ trait FreshRanges {
 }
 
 impl FreshRanges for Vec<Range> {
-    fn parse<'a>(inp: impl Iterator<Item = &'a str>) -> Self {
-        let mut res: Vec<Range> = inp.map(|x| x.parse().unwrap()).collect();
-        res.collapse()
+    fn parse<'a>(inp: impl Iterator<Item = &'a str>) -> Result<Self, AocError> {
+        let mut res: Vec<Range> = parse_split(inp).collect::<Result<_, _>>()?;
+        Ok(res.collapse())
     }
 
     fn collapse(&mut self) -> Self {
@@ -96,7 +125,7 @@ impl FreshRanges for Vec<Range> {
                     max: new_max,
                 });
             } else {
-                res.push(range.clone());
+                res.push(*range);
             }
         }
 
@@ -135,32 +164,3 @@ impl FreshRanges for Vec<Range> {
         self.iter().map(|range| range.count()).sum()
     }
 }
-
-#[cfg(test)]
-mod test {
-    use crate::y2025::day5::{FreshRanges, parse};
-
-    const TEST_INPUT: &str = "3-5
-10-14
-16-20
-12-18
-
-1
-5
-8
-11
-17
-32";
-
-    #[test]
-    fn test_count_fresh() {
-        let (ranges, ingredients) = parse(TEST_INPUT);
-        assert_eq!(3, ranges.count_fresh(&ingredients));
-    }
-
-    #[test]
-    fn test_total_fresh() {
-        let (ranges, _) = parse(TEST_INPUT);
-        assert_eq!(14, ranges.total_fresh());
-    }
-}
@@ -1,13 +1,35 @@
 use std::str::FromStr;
 
-use crate::helpers::grid::Grid;
+use crate::{
+    answer::Answer,
+    error::AocError,
+    helpers::grid::Grid,
+    solver::{Context, DynSolver, Solver},
+};
 
-const INPUT: &str = include_str!("input/day6.txt");
+pub(crate) static SOLVER: &dyn DynSolver = &Day6;
 
-pub fn run() -> (u64, u64) {
-    let worksheet: Worksheet = INPUT.parse().unwrap();
-    let worksheet2 = Worksheet::from_grid(&(INPUT.parse().unwrap()));
-    (worksheet.caclulate(), worksheet2.caclulate())
+struct Day6;
+
+impl Solver for Day6 {
+    const YEAR: u16 = 2025;
+    const DAY: u32 = 6;
+
+    type Input<'a> = (Worksheet, Worksheet);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let worksheet: Worksheet = input.parse()?;
+        let worksheet2 = Worksheet::from_grid(&(input.parse()?))?;
+        Ok((worksheet, worksheet2))
+    }
+
+    fn part1((worksheet, _): &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(worksheet.caclulate().into())
+    }
+
+    fn part2((_, worksheet2): &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(worksheet2.caclulate().into())
+    }
 }
 
 #[derive(Debug)]
@@ -16,34 +38,47 @@ struct Worksheet {
     operations: Vec<char>,
 }
 
-#[derive(Debug)]
-enum WorksheetParseError {
-    EmptyInput,
+fn is_operation(c: char) -> bool {
+    c == '+' || c == '*'
 }
 
 impl FromStr for Worksheet {
-    type Err = WorksheetParseError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut rows: Vec<&str> = s.split('\n').collect();
+        let last_line = rows.len();
         let operations: Vec<char> = match rows.pop() {
-            Some(s) => s
-                .split(' ')
-                .filter(|x| !x.is_empty())
-                .map(|x| x.chars().next().unwrap())
-                .collect(),
-            None => return Err(WorksheetParseError::EmptyInput),
+            Some(s) => s.split(' ').filter_map(|x| x.chars().next()).collect(),
+            None => return Err(AocError::parse("empty worksheet")),
         };
+        if let Some(op) = operations.iter().find(|c| !is_operation(**c)) {
+            return Err(AocError::parse_line(
+                last_line,
+                format!("unexpected operation {op}"),
+            ));
+        }
+
         let count = operations.len();
         let rows: Vec<Vec<u64>> = rows
             .iter()
-            .map(|s| {
-                s.split(' ')
+            .enumerate()
+            .map(|(idx, s)| {
+                let row = s
+                    .split(' ')
                     .filter(|x| !x.trim().is_empty())
-                    .map(|x| x.trim().parse().unwrap())
-                    .collect()
+                    .map(|x| x.trim().parse())
+                    .collect::<Result<Vec<u64>, _>>()
+                    .map_err(|_| AocError::parse_line(idx + 1, "expected integers"))?;
+                if row.len() != count {
+                    return Err(AocError::parse_line(
+                        idx + 1,
+                        format!("expected {count} values"),
+                    ));
+                }
+                Ok(row)
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
         let cols: Vec<Vec<u64>> = (0..count)
             .map(|col| rows.iter().map(|r| r[col]).collect())
@@ -53,24 +88,45 @@ impl FromStr for Worksheet {
 }
 
 impl Worksheet {
-    fn from_grid(grid: &Grid<char>) -> Self {
+    fn from_grid(grid: &Grid<char>) -> Result<Self, AocError> {
         let mut operations: Vec<char> = Vec::new();
         let mut cols: Vec<Vec<u64>> = Vec::new();
-        let max_row = grid.rows() - 1;
+        let max_row = match grid.rows() {
+            0 => return Err(AocError::parse("empty worksheet")),
+            r => r - 1,
+        };
         for (p, v) in grid.walk_row(max_row) {
             if v != ' ' && v != '.' {
+                if !is_operation(v) {
+                    return Err(AocError::parse_at(
+                        max_row + 1,
+                        p.x as usize + 1,
+                        format!("unexpected operation {v}"),
+                    ));
+                }
                 operations.push(v);
                 cols.push(Vec::new());
             }
 
-            let col = cols.last_mut().unwrap();
+            let col = match cols.last_mut() {
+                Some(c) => c,
+                None => {
+                    return Err(AocError::parse_at(
+                        max_row + 1,
+                        p.x as usize + 1,
+                        "expected an operation",
+                    ));
+                }
+            };
             let mut q = p.up();
             let mut base = 1;
             let mut val: u64 = 0;
-            while grid.contains(q) {
-                let v = grid.get(q).unwrap();
+            while let Some(v) = grid.get(q) {
                 if v != ' ' && v != '.' {
-                    val += (v.to_digit(10).unwrap() as u64) * base;
+                    let digit = v.to_digit(10).ok_or_else(|| {
+                        AocError::parse_at(q.y as usize + 1, q.x as usize + 1, "expected a digit")
+                    })?;
+                    val += (digit as u64) * base;
                     base *= 10;
                 }
                 q = q.up();
@@ -80,7 +136,7 @@ impl Worksheet {
             }
         }
 
-        Worksheet { cols, operations }
+        Ok(Worksheet { cols, operations })
     }
 
     fn len(&self) -> usize {
@@ -101,24 +157,3 @@ impl Worksheet {
         (0..self.len()).map(|x| self.calculate_column(x)).sum()
     }
 }
-
-#[cfg(test)]
-mod test {
-    use crate::y2025::day6::Worksheet;
-
-    const TEST_INPUT: &str = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
-
-    #[test]
-    fn test_calculate() {
-        let worksheet: Worksheet = TEST_INPUT.parse().unwrap();
-        assert_eq!(4277556, worksheet.caclulate());
-    }
-
-    #[test]
-    fn test_calculate_from_grid() {
-        let grid = TEST_INPUT.parse().unwrap();
-        let worksheet = Worksheet::from_grid(&grid);
-        println!("{worksheet:?}");
-        assert_eq!(3263827, worksheet.caclulate());
-    }
-}
@@ -1,22 +1,44 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::helpers::{
-    grid::{Grid, Point},
-    hashmap::CountMap,
+use crate::{
+    answer::Answer,
+    error::AocError,
+    helpers::{
+        grid::{Grid, Point},
+        hashmap::CountMap,
+    },
+    solver::{Context, DynSolver, Solver},
 };
 
-const INPUT: &str = include_str!("input/day7.txt");
+pub(crate) static SOLVER: &dyn DynSolver = &Day7;
 
-pub fn run() -> (u64, u64) {
-    let manifold = parse(INPUT);
-    (
-        manifold.count_beam_splits() as u64,
-        manifold.count_quantum_timelines() as u64,
-    )
+struct Day7;
+
+impl Solver for Day7 {
+    const YEAR: u16 = 2025;
+    const DAY: u32 = 7;
+
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse(input)
+    }
+
+    fn part1(manifold: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(manifold.count_beam_splits().into())
+    }
+
+    fn part2(manifold: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(manifold.count_quantum_timelines().into())
+    }
 }
 
-fn parse(input: &str) -> impl TachyonManifold {
-    input.parse::<Grid<char>>().unwrap()
+fn parse(input: &str) -> Result<Grid<char>, AocError> {
+    let manifold = input.parse::<Grid<char>>()?;
+    if manifold.search('S').is_none() {
+        return Err(AocError::parse("no start 'S' in manifold"));
+    }
+    Ok(manifold)
 }
 
 trait TachyonManifold {
@@ -26,6 +48,7 @@ trait TachyonManifold {
 }
 
 impl TachyonManifold for Grid<char> {
+    /// The start is checked for when parsing, so it is always present.
     fn find_start(&self) -> Point {
         self.search('S').unwrap()
     }
@@ -119,40 +142,6 @@ impl TachyonManifold for Grid<char> {
         }
 
         // print!("{dbg_grid}");
-        timelines.iter().map(|(_, v)| *v).sum()
-    }
-}
-
-#[cfg(test)]
-mod test {
-    use crate::y2025::day7::{TachyonManifold, parse};
-
-    const TEST_INPUT: &str = ".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............";
-
-    #[test]
-    fn test_count_beam_splits() {
-        let manifold = parse(TEST_INPUT);
-        assert_eq!(21, manifold.count_beam_splits());
-    }
-
-    #[test]
-    fn test_count_quantum_timelines() {
-        let manifold = parse(TEST_INPUT);
-        assert_eq!(40, manifold.count_quantum_timelines());
+        timelines.values().copied().sum()
     }
 }
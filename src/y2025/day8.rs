@@ -2,24 +2,59 @@ use std::{cmp::Reverse, collections::BinaryHeap, num::ParseIntError, str::FromSt
 
 use itertools::Itertools;
 
-use crate::helpers::{disjointset::DisjointSet, parse::parse_lines};
-
-const INPUT: &str = include_str!("input/day8.txt");
+use crate::{
+    answer::Answer,
+    error::AocError,
+    helpers::{disjointset::DisjointSet, parse::parse_lines},
+    solver::{Context, DynSolver, Param, Solver},
+};
+
+pub(crate) static SOLVER: &dyn DynSolver = &Day8;
+
+struct Day8;
+
+impl Solver for Day8 {
+    const YEAR: u16 = 2025;
+    const DAY: u32 = 8;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "connections",
+            default: "1000",
+            help: "shortest connections to make in part 1",
+        },
+        Param {
+            name: "top",
+            default: "3",
+            help: "largest circuits to multiply in part 1",
+        },
+    ];
+
+    type Input<'a> = Vec<JunctionBox>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse(input)
+    }
 
-pub fn run() -> (u64, u64) {
-    let circuits = parse(INPUT);
-    let mut connections = circuits.all_pairs();
-    let last_join = connections.join_all_circuits(&circuits).unwrap();
-    let mut connections = circuits.all_pairs();
+    fn part1(circuits: &Self::Input<'_>, ctx: &Context) -> Result<Answer, AocError> {
+        let mut connections = circuits.all_pairs();
+        Ok(connections
+            .join_circuits(ctx.param("connections")?)
+            .product_top_circuits(ctx.param("top")?)
+            .into())
+    }
 
-    (
-        connections.join_circuits(1000).product_top_circuits(3) as u64,
-        last_join.0.wall_distance(&last_join.1),
-    )
+    fn part2(circuits: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        let mut connections = circuits.all_pairs();
+        let last_join = connections
+            .join_all_circuits(circuits)
+            .ok_or_else(|| AocError::solver("junction boxes never form a single circuit"))?;
+        Ok(last_join.0.wall_distance(&last_join.1).into())
+    }
 }
 
-fn parse(inp: &str) -> impl JunctionBoxes {
-    parse_lines(inp).collect::<Vec<JunctionBox>>()
+fn parse(inp: &str) -> Result<Vec<JunctionBox>, AocError> {
+    parse_lines(inp).collect()
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
@@ -36,8 +71,8 @@ impl JunctionBox {
             .unwrap()
     }
 
-    fn wall_distance(&self, other: &JunctionBox) -> u64 {
-        (self.x * other.x).try_into().unwrap()
+    fn wall_distance(&self, other: &JunctionBox) -> i64 {
+        self.x * other.x
     }
 
     fn from_slice(vals: &[i64]) -> Result<Self, JunctionBoxError> {
@@ -132,7 +167,9 @@ impl Connections for BinaryHeap<Reverse<JunctionBoxConnection>> {
         // let mut pairs = self.iter();
         let mut circuits: DisjointSet<JunctionBox> = DisjointSet::new();
         for _ in 0..num_circuits {
-            let conn = self.pop().unwrap().0;
+            let Some(Reverse(conn)) = self.pop() else {
+                break;
+            };
             circuits.add_connection(&conn);
         }
 
@@ -168,28 +205,7 @@ impl Circuits for DisjointSet<JunctionBox> {
 
 #[cfg(test)]
 mod test {
-    use crate::y2025::day8::{Circuits, Connections, JunctionBox, JunctionBoxes, parse};
-
-    const TEST_INPUT: &str = "162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689";
+    use crate::y2025::day8::JunctionBox;
 
     #[test]
     fn test_distance() {
@@ -197,21 +213,4 @@ mod test {
         let q = JunctionBox::from_slice(&[431, 825, 988]).unwrap();
         assert_eq!(103401, p.distance(&q));
     }
-
-    #[test]
-    fn test_join_circuits() {
-        let circuits = parse(TEST_INPUT);
-        let prod: usize = circuits
-            .all_pairs()
-            .join_circuits(10)
-            .product_top_circuits(3);
-        assert_eq!(40, prod);
-    }
-
-    #[test]
-    fn test_join_all_circuits() {
-        let circuits = parse(TEST_INPUT);
-        let last_join = circuits.all_pairs().join_all_circuits(&circuits).unwrap();
-        assert_eq!(25272, last_join.0.wall_distance(&last_join.1));
-    }
 }
@@ -1,17 +1,40 @@
 use itertools::Itertools;
 
-use crate::helpers::{grid::Point, parse::parse_lines};
+use crate::{
+    answer::Answer,
+    error::AocError,
+    helpers::{grid::Point, parse::parse_lines},
+    solver::{Context, DynSolver, Solver},
+};
 
-const INPUT: &str = include_str!("input/day9.txt");
+pub(crate) static SOLVER: &dyn DynSolver = &Day9;
 
-pub fn run() -> (u64, u64) {
-    let floor = parse(INPUT);
-    (floor.max_rectangle(), 0)
+struct Day9;
+
+impl Solver for Day9 {
+    const YEAR: u16 = 2025;
+    const DAY: u32 = 9;
+
+    type Input<'a> = Vec<Point>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse(input)
+    }
+
+    fn part1(floor: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        let area = floor
+            .max_rectangle()
+            .ok_or_else(|| AocError::solver("need at least two red tiles"))?;
+        Ok(area.into())
+    }
+
+    fn part2(_: &Self::Input<'_>, _: &Context) -> Result<Answer, AocError> {
+        Ok(Answer::NotImplemented)
+    }
 }
 
-fn parse(input: &str) -> impl TheaterFloor {
-    let x: Vec<Point> = parse_lines(input).collect();
-    x
+fn parse(input: &str) -> Result<Vec<Point>, AocError> {
+    parse_lines(input).collect()
 }
 
 trait Rectangle {
@@ -26,35 +49,14 @@ impl Rectangle for Point {
 }
 
 trait TheaterFloor {
-    fn max_rectangle(&self) -> u64;
+    fn max_rectangle(&self) -> Option<u64>;
 }
 
 impl TheaterFloor for Vec<Point> {
-    fn max_rectangle(&self) -> u64 {
+    fn max_rectangle(&self) -> Option<u64> {
         self.iter()
             .combinations(2)
             .map(|pair| pair[0].area(pair[1]))
             .max()
-            .unwrap()
-    }
-}
-
-#[cfg(test)]
-mod test {
-    use crate::y2025::day9::{TheaterFloor, parse};
-
-    const TEST_INPUT: &str = "7,1
-11,1
-11,7
-9,7
-9,5
-2,5
-2,3
-7,3";
-
-    #[test]
-    fn test_max_rectangle() {
-        let t = parse(TEST_INPUT);
-        assert_eq!(50, t.max_rectangle());
     }
 }